@@ -106,7 +106,10 @@ fn setup_player(mut commands: Commands) {
             Visibility::default(),
             LockedAxes::ROTATION_LOCKED,
             (
-                input_transition!(() <=> (>Walking), Axis2D[binding2d::wasd()]),
+                input_transition!(
+                    () <=> (>Walking),
+                    Axis2D[binding2d::wasd(), binding2d::left_stick()]
+                ),
                 input_transition!(() <=> (Sprinting), Axis1D[binding1d::left_shift()]),
                 input!(Look, Axis2D[binding2d::mouse_move()]),
                 input!(
@@ -161,6 +164,7 @@ fn walk(
 }
 
 #[derive(Action)]
+#[action(output = Vec2)]
 pub struct Look;
 
 fn look(
@@ -171,7 +175,7 @@ fn look(
     let (mut player_transform, player) = players.get_mut(look.input)?;
     let (mut camera_transform, mut pitch) = cameras.get_mut(player.camera)?;
 
-    let input = look.data.as_2d_ok()?;
+    let input = look.data;
     let delta_yaw = -input.x * player.look_sensitivity;
     let delta_pitch = -input.y * player.look_sensitivity;
 
@@ -0,0 +1,139 @@
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
+use bevy::prelude::*;
+
+use crate::actions::{Action, ActionData, ActionOutput};
+use crate::bundles::add_systems_once_to_world;
+use crate::{BindingPartSystems, PrettyNiceInputSystems};
+
+/// Opt-in component for reading an [`Action`] from ordinary systems instead of observing its events.
+///
+/// Insert it on the input system entity, and it'll be kept up to date before [`Update`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn((
+///         ActionState::<Jump>::default(),
+///         input!(Jump, Axis1D[binding1d::space()]),
+///     ));
+/// }
+///
+/// fn jump(players: Query<&ActionState<Jump>>) {
+///     for jump in players.iter() {
+///         if jump.just_pressed() {
+///             info!("Jumped");
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[component(on_add)]
+pub struct ActionState<A: Action> {
+    value: Option<ActionData>,
+    prev: Option<ActionData>,
+    just_pressed: bool,
+    just_released: bool,
+    pressed_at: Option<Duration>,
+    held: Duration,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Action> Default for ActionState<A> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            prev: None,
+            just_pressed: false,
+            just_released: false,
+            pressed_at: None,
+            held: Duration::ZERO,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Action> ActionState<A> {
+    /// The current value, if the action has received any input yet.
+    pub fn value(&self) -> Option<A::Output> {
+        self.value.map(A::Output::from_action_data)
+    }
+
+    /// The value at the end of the previous frame.
+    pub fn prev_value(&self) -> Option<A::Output> {
+        self.prev.map(A::Output::from_action_data)
+    }
+
+    pub fn pressed(&self) -> bool {
+        self.value.is_some_and(|value| !value.is_zero())
+    }
+
+    /// Whether the action was pressed this frame, same as [`JustPressed`](crate::prelude::JustPressed).
+    pub fn just_pressed(&self) -> bool {
+        self.just_pressed
+    }
+
+    /// Whether the action was released this frame, same as [`JustReleased`](crate::prelude::JustReleased).
+    pub fn just_released(&self) -> bool {
+        self.just_released
+    }
+
+    /// The [`Time::elapsed`] when the action was pressed, if it's pressed.
+    pub fn pressed_at(&self) -> Option<Duration> {
+        self.pressed_at
+    }
+
+    /// How long the action has been held, or how long it was held for if it's been released.
+    pub fn held_duration(&self) -> Duration {
+        self.held
+    }
+
+    pub(crate) fn update(&mut self, data: ActionData, prev: Option<ActionData>, now: Duration) {
+        self.value = Some(data);
+        let Some(prev) = prev else {
+            return;
+        };
+
+        if !data.is_zero() && prev.is_zero() {
+            self.just_pressed = true;
+            self.pressed_at = Some(now);
+            self.held = Duration::ZERO;
+        }
+        if data.is_zero() && !prev.is_zero() {
+            self.just_released = true;
+            if let Some(pressed_at) = self.pressed_at.take() {
+                self.held = now.saturating_sub(pressed_at);
+            }
+        }
+    }
+
+    fn on_add(mut world: DeferredWorld, _context: HookContext) {
+        world.commands().queue(|world: &mut World| {
+            add_systems_once_to_world(world, TypeId::of::<Self>(), PreUpdate, || {
+                tick_action_state::<A>
+                    .in_set(PrettyNiceInputSystems)
+                    .before(BindingPartSystems)
+            });
+        });
+    }
+}
+
+fn tick_action_state<A: Action>(mut states: Query<&mut ActionState<A>>, time: Res<Time>) {
+    for mut state in states.iter_mut() {
+        state.prev = state.value;
+        state.just_pressed = false;
+        state.just_released = false;
+        if let Some(pressed_at) = state.pressed_at {
+            state.held = time.elapsed().saturating_sub(pressed_at);
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use std::marker::PhantomData;
 
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::world::DeferredWorld;
 use bevy::prelude::*;
 
+use crate::bundles::add_observer_once;
 use crate::conditions::Condition;
 
 /// Wrapper for each supported datatype returned from bindings.
@@ -104,6 +107,24 @@ impl ActionData {
         self.length() > threshold
     }
 
+    /// All the axes as a [`Vec3`], with missing axes set to zero.
+    pub fn axes(&self) -> Vec3 {
+        match self {
+            ActionData::Axis1D(value) => Vec3::new(*value, 0.0, 0.0),
+            ActionData::Axis2D(value) => value.extend(0.0),
+            ActionData::Axis3D(value) => *value,
+        }
+    }
+
+    /// The same dimension as `self`, filled with the axes it has from `axes`.
+    pub fn with_axes(&self, axes: Vec3) -> Self {
+        match self {
+            ActionData::Axis1D(_) => ActionData::Axis1D(axes.x),
+            ActionData::Axis2D(_) => ActionData::Axis2D(axes.truncate()),
+            ActionData::Axis3D(_) => ActionData::Axis3D(axes),
+        }
+    }
+
     pub fn debug_name(&self) -> &'static str {
         match self {
             ActionData::Axis1D(_) => "Axis1D",
@@ -126,7 +147,81 @@ pub trait Action: Send + Sync + 'static {
     /// Which filter determines how enabled/disabled input is processed.
     ///
     /// Generally, this should either be [`IsInputEnabled`](crate::prelude::IsInputEnabled) or [`IsInputEnabledInvalidate`](crate::prelude::IsInputEnabledInvalidate).
-    type EnableFilter: Condition;
+    type EnableFilter: Condition + Component + Default;
+
+    /// What type the action's events carry their data as.
+    ///
+    /// Generally, this is [`ActionData`] unless set with `#[action(output = ...)]` on the derive.
+    type Output: ActionOutput;
+}
+
+/// Type an [`Action`]'s events can carry their data as, converted from [`ActionData`].
+pub trait ActionOutput: Copy + std::fmt::Debug + PartialEq + Send + Sync + 'static {
+    /// How many parts its bindings need, or `None` if it can be made from any of them.
+    ///
+    /// This is the runtime version of [`OutputDimension`](crate::dimension::OutputDimension), for bindings that aren't known at compile time.
+    const DIMENSION: Option<usize> = None;
+
+    fn from_action_data(data: ActionData) -> Self;
+
+    fn into_action_data(self) -> ActionData;
+}
+
+impl ActionOutput for ActionData {
+    fn from_action_data(data: ActionData) -> Self {
+        data
+    }
+
+    fn into_action_data(self) -> ActionData {
+        self
+    }
+}
+
+/// Whether the input is nonzero.
+impl ActionOutput for bool {
+    fn from_action_data(data: ActionData) -> Self {
+        !data.is_zero()
+    }
+
+    fn into_action_data(self) -> ActionData {
+        ActionData::x(if self { 1.0 } else { 0.0 })
+    }
+}
+
+impl ActionOutput for f32 {
+    const DIMENSION: Option<usize> = Some(1);
+
+    fn from_action_data(data: ActionData) -> Self {
+        data.axes().x
+    }
+
+    fn into_action_data(self) -> ActionData {
+        ActionData::x(self)
+    }
+}
+
+impl ActionOutput for Vec2 {
+    const DIMENSION: Option<usize> = Some(2);
+
+    fn from_action_data(data: ActionData) -> Self {
+        data.axes().xy()
+    }
+
+    fn into_action_data(self) -> ActionData {
+        ActionData::xy(self.x, self.y)
+    }
+}
+
+impl ActionOutput for Vec3 {
+    const DIMENSION: Option<usize> = Some(3);
+
+    fn from_action_data(data: ActionData) -> Self {
+        data.axes()
+    }
+
+    fn into_action_data(self) -> ActionData {
+        ActionData::xyz(self.x, self.y, self.z)
+    }
 }
 
 #[derive(Component, Debug, Reflect)]
@@ -137,4 +232,51 @@ pub struct Actions<A: Action>(#[relationship] Vec<Entity>, PhantomData<A>);
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 #[relationship(relationship_target = Actions<A>)]
+#[component(on_add, on_remove)]
 pub struct ActionOf<A: Action>(#[relationship] pub Entity, PhantomData<A>);
+
+impl<A: Action> ActionOf<A> {
+    fn on_add(mut world: DeferredWorld, context: HookContext) {
+        let input = world.get::<Self>(context.entity).unwrap().0;
+        world.commands().entity(context.entity).insert((
+            AnyActionOf(input),
+            add_observer_once::<Self>(|| Observer::new(Self::on_insert_again)),
+        ));
+    }
+
+    /// Moves [`AnyActionOf`] along with [`ActionOf`] when it's replaced, which doesn't run `on_add`.
+    ///
+    /// This is one global observer per action because relationships already use the `on_insert` and `on_replace` hooks.
+    fn on_insert_again(
+        insert: On<Insert, Self>,
+        actions: Query<(&Self, Option<&AnyActionOf>)>,
+        mut commands: Commands,
+    ) -> Result {
+        let (action_of, any_action_of) = actions.get(insert.entity)?;
+        if any_action_of.is_none_or(|any_action_of| any_action_of.0 != action_of.0) {
+            commands
+                .entity(insert.entity)
+                .insert(AnyActionOf(action_of.0));
+        }
+        Ok(())
+    }
+
+    fn on_remove(mut world: DeferredWorld, context: HookContext) {
+        world
+            .commands()
+            .entity(context.entity)
+            .try_remove::<AnyActionOf>();
+    }
+}
+
+/// Untyped version of [`Actions`], for systems that need to walk from an input system entity to all of its actions.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+#[relationship_target(relationship = AnyActionOf)]
+pub struct AnyActions(#[relationship] Vec<Entity>);
+
+/// Untyped version of [`ActionOf`], inserted alongside it.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+#[relationship(relationship_target = AnyActions)]
+pub struct AnyActionOf(#[relationship] pub Entity);
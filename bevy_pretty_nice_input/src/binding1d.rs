@@ -1,12 +1,12 @@
 //! 1-dimensional bindings, such as a single key press or joystick axis.
 
-use bevy::ecs::spawn::SpawnableList;
 use bevy::prelude::*;
 
-use crate::binding_parts::{AxisDirection, BindingPartData, BindingPartOf, MouseScrollDirection};
+use crate::binding_parts::{AxisDirection, BindingPartData, MouseScrollDirection};
+use crate::dimension::{Axis1D, BindingList};
 
 /// Binding for a single key in the range \[0,1\].
-pub fn key(key: KeyCode) -> impl SpawnableList<BindingPartOf> {
+pub fn key(key: KeyCode) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Key {:?}", key)),
         BindingPartData::default(),
@@ -15,7 +15,7 @@ pub fn key(key: KeyCode) -> impl SpawnableList<BindingPartOf> {
 }
 
 /// Binding for two keys in the range \[-1,1\], with one being positive and the other negative.
-pub fn key_axis(key_pos: KeyCode, key_neg: KeyCode) -> impl SpawnableList<BindingPartOf> {
+pub fn key_axis(key_pos: KeyCode, key_neg: KeyCode) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Key Axis {:?} / {:?}", key_pos, key_neg)),
         BindingPartData::default(),
@@ -24,7 +24,7 @@ pub fn key_axis(key_pos: KeyCode, key_neg: KeyCode) -> impl SpawnableList<Bindin
 }
 
 /// Binding for a single gamepad axis in the range \[-1,1\].
-pub fn gamepad_axis(axis: GamepadAxis) -> impl SpawnableList<BindingPartOf> {
+pub fn gamepad_axis(axis: GamepadAxis) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Gamepad Axis {:?}", axis)),
         BindingPartData::default(),
@@ -32,8 +32,26 @@ pub fn gamepad_axis(axis: GamepadAxis) -> impl SpawnableList<BindingPartOf> {
     ))
 }
 
+/// Binding for a single gamepad button in the range \[0,1\], which is analog for triggers.
+pub fn gamepad_button(button: GamepadButton) -> impl BindingList<Axis1D> {
+    Spawn((
+        Name::new(format!("Gamepad Button {:?}", button)),
+        BindingPartData::default(),
+        crate::binding_parts::GamepadButton(button),
+    ))
+}
+
+/// Binding for two gamepad buttons in the range \[-1,1\], with one being positive and the other negative. Analog for triggers.
+pub fn gamepad_button_axis(pos: GamepadButton, neg: GamepadButton) -> impl BindingList<Axis1D> {
+    Spawn((
+        Name::new(format!("Gamepad Button Axis {:?} / {:?}", pos, neg)),
+        BindingPartData::default(),
+        crate::binding_parts::GamepadButtonAxis::new(pos, neg),
+    ))
+}
+
 /// Binding for a single mouse button in the range \[0,1\].
-pub fn mouse_button(button: MouseButton) -> impl SpawnableList<BindingPartOf> {
+pub fn mouse_button(button: MouseButton) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Mouse Button {:?}", button)),
         BindingPartData::default(),
@@ -41,8 +59,8 @@ pub fn mouse_button(button: MouseButton) -> impl SpawnableList<BindingPartOf> {
     ))
 }
 
-/// Binding for a single axis of mouse movement in the range \[-inf,inf\].
-pub fn mouse_move_axis(axis: AxisDirection) -> impl SpawnableList<BindingPartOf> {
+/// Binding for a single axis of mouse movement in the range \[-inf,inf\], accumulated over each frame.
+pub fn mouse_move_axis(axis: AxisDirection) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Mouse Move Axis {:?}", axis)),
         BindingPartData::default(),
@@ -51,7 +69,7 @@ pub fn mouse_move_axis(axis: AxisDirection) -> impl SpawnableList<BindingPartOf>
 }
 
 /// Binding for a single direction of mouse scroll in the range \[0,inf\].
-pub fn mouse_scroll(direction: MouseScrollDirection) -> impl SpawnableList<BindingPartOf> {
+pub fn mouse_scroll(direction: MouseScrollDirection) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Mouse Scroll {:?}", direction)),
         BindingPartData::default(),
@@ -60,7 +78,7 @@ pub fn mouse_scroll(direction: MouseScrollDirection) -> impl SpawnableList<Bindi
 }
 
 /// Binding for a single axis of mouse scroll in the range \[-inf,inf\].
-pub fn mouse_scroll_axis(axis: AxisDirection) -> impl SpawnableList<BindingPartOf> {
+pub fn mouse_scroll_axis(axis: AxisDirection) -> impl BindingList<Axis1D> {
     Spawn((
         Name::new(format!("Mouse Scroll Axis {:?}", axis)),
         BindingPartData::default(),
@@ -68,42 +86,98 @@ pub fn mouse_scroll_axis(axis: AxisDirection) -> impl SpawnableList<BindingPartO
     ))
 }
 
-pub fn space() -> impl SpawnableList<BindingPartOf> {
+pub fn space() -> impl BindingList<Axis1D> {
     key(KeyCode::Space)
 }
 
-pub fn left_shift() -> impl SpawnableList<BindingPartOf> {
+pub fn left_shift() -> impl BindingList<Axis1D> {
     key(KeyCode::ShiftLeft)
 }
 
-pub fn left_ctrl() -> impl SpawnableList<BindingPartOf> {
+pub fn left_ctrl() -> impl BindingList<Axis1D> {
     key(KeyCode::ControlLeft)
 }
 
-pub fn left_click() -> impl SpawnableList<BindingPartOf> {
+pub fn left_click() -> impl BindingList<Axis1D> {
     mouse_button(MouseButton::Left)
 }
 
-pub fn right_click() -> impl SpawnableList<BindingPartOf> {
+pub fn right_click() -> impl BindingList<Axis1D> {
     mouse_button(MouseButton::Right)
 }
 
-pub fn middle_click() -> impl SpawnableList<BindingPartOf> {
+pub fn middle_click() -> impl BindingList<Axis1D> {
     mouse_button(MouseButton::Middle)
 }
 
-pub fn scroll_up() -> impl SpawnableList<BindingPartOf> {
+pub fn south() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::South)
+}
+
+pub fn east() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::East)
+}
+
+pub fn north() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::North)
+}
+
+pub fn west() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::West)
+}
+
+pub fn left_bumper() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::LeftTrigger)
+}
+
+pub fn right_bumper() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::RightTrigger)
+}
+
+pub fn left_trigger() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::LeftTrigger2)
+}
+
+pub fn right_trigger() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::RightTrigger2)
+}
+
+pub fn dpad_up() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::DPadUp)
+}
+
+pub fn dpad_down() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::DPadDown)
+}
+
+pub fn dpad_left() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::DPadLeft)
+}
+
+pub fn dpad_right() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::DPadRight)
+}
+
+pub fn start() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::Start)
+}
+
+pub fn select() -> impl BindingList<Axis1D> {
+    gamepad_button(GamepadButton::Select)
+}
+
+pub fn scroll_up() -> impl BindingList<Axis1D> {
     mouse_scroll(MouseScrollDirection::Up)
 }
 
-pub fn scroll_down() -> impl SpawnableList<BindingPartOf> {
+pub fn scroll_down() -> impl BindingList<Axis1D> {
     mouse_scroll(MouseScrollDirection::Down)
 }
 
-pub fn scroll_horizontal() -> impl SpawnableList<BindingPartOf> {
+pub fn scroll_horizontal() -> impl BindingList<Axis1D> {
     mouse_scroll_axis(AxisDirection::X)
 }
 
-pub fn scroll_vertical() -> impl SpawnableList<BindingPartOf> {
+pub fn scroll_vertical() -> impl BindingList<Axis1D> {
     mouse_scroll_axis(AxisDirection::Y)
 }
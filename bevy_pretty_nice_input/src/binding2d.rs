@@ -1,28 +1,60 @@
 //! 2-dimensional bindings, such as WASD or an entire joystick.
 
-use bevy::ecs::spawn::SpawnableList;
 use bevy::prelude::*;
 
-use crate::binding_parts::{AxisDirection, BindingPartOf};
+use crate::binding_parts::AxisDirection;
 use crate::binding1d::*;
+use crate::dimension::{Axis1D, Axis2D, BindingList};
 
-pub fn wasd() -> impl SpawnableList<BindingPartOf> {
+/// Composes any two 1-dimensional bindings into x and y.
+pub fn from_parts(
+    x: impl BindingList<Axis1D>,
+    y: impl BindingList<Axis1D>,
+) -> impl BindingList<Axis2D> {
+    (x, y)
+}
+
+pub fn wasd() -> impl BindingList<Axis2D> {
     (
         key_axis(KeyCode::KeyD, KeyCode::KeyA),
         key_axis(KeyCode::KeyW, KeyCode::KeyS),
     )
 }
 
-pub fn arrow_keys() -> impl SpawnableList<BindingPartOf> {
+pub fn arrow_keys() -> impl BindingList<Axis2D> {
     (
         key_axis(KeyCode::ArrowRight, KeyCode::ArrowLeft),
         key_axis(KeyCode::ArrowUp, KeyCode::ArrowDown),
     )
 }
 
-pub fn mouse_move() -> impl SpawnableList<BindingPartOf> {
+pub fn mouse_move() -> impl BindingList<Axis2D> {
     (
         mouse_move_axis(AxisDirection::X),
         mouse_move_axis(AxisDirection::Y),
     )
 }
+
+/// Left stick, with up being positive Y like [`wasd`].
+pub fn left_stick() -> impl BindingList<Axis2D> {
+    (
+        gamepad_axis(GamepadAxis::LeftStickX),
+        gamepad_axis(GamepadAxis::LeftStickY),
+    )
+}
+
+/// Right stick, with up being positive Y like [`wasd`].
+pub fn right_stick() -> impl BindingList<Axis2D> {
+    (
+        gamepad_axis(GamepadAxis::RightStickX),
+        gamepad_axis(GamepadAxis::RightStickY),
+    )
+}
+
+/// D-pad, with up being positive Y like [`wasd`].
+pub fn dpad() -> impl BindingList<Axis2D> {
+    (
+        gamepad_button_axis(GamepadButton::DPadRight, GamepadButton::DPadLeft),
+        gamepad_button_axis(GamepadButton::DPadUp, GamepadButton::DPadDown),
+    )
+}
@@ -0,0 +1,54 @@
+//! 3-dimensional bindings, such as a fly camera moving on every axis.
+//!
+//! X is right, Y is up and Z is forward. Bevy's forward is -Z, so negate Z when applying the value to a
+//! [`Transform`].
+
+use bevy::prelude::*;
+
+use crate::binding1d::*;
+use crate::dimension::{Axis1D, Axis3D, BindingList};
+
+/// Composes any three 1-dimensional bindings into x, y and z.
+pub fn from_parts(
+    x: impl BindingList<Axis1D>,
+    y: impl BindingList<Axis1D>,
+    z: impl BindingList<Axis1D>,
+) -> impl BindingList<Axis3D> {
+    (x, y, z)
+}
+
+/// WASD for horizontal movement, space to go up and left control to go down.
+pub fn wasd_space_ctrl() -> impl BindingList<Axis3D> {
+    (
+        key_axis(KeyCode::KeyD, KeyCode::KeyA),
+        key_axis(KeyCode::Space, KeyCode::ControlLeft),
+        key_axis(KeyCode::KeyW, KeyCode::KeyS),
+    )
+}
+
+/// WASD for horizontal movement, E to go up and Q to go down.
+pub fn wasd_qe() -> impl BindingList<Axis3D> {
+    (
+        key_axis(KeyCode::KeyD, KeyCode::KeyA),
+        key_axis(KeyCode::KeyE, KeyCode::KeyQ),
+        key_axis(KeyCode::KeyW, KeyCode::KeyS),
+    )
+}
+
+/// Left stick for horizontal movement, right trigger to go up and left trigger to go down.
+pub fn left_stick_triggers() -> impl BindingList<Axis3D> {
+    (
+        gamepad_axis(GamepadAxis::LeftStickX),
+        gamepad_button_axis(GamepadButton::RightTrigger2, GamepadButton::LeftTrigger2),
+        gamepad_axis(GamepadAxis::LeftStickY),
+    )
+}
+
+/// Left stick for horizontal movement, right bumper to go up and left bumper to go down.
+pub fn left_stick_bumpers() -> impl BindingList<Axis3D> {
+    (
+        gamepad_axis(GamepadAxis::LeftStickX),
+        gamepad_button_axis(GamepadButton::RightTrigger, GamepadButton::LeftTrigger),
+        gamepad_axis(GamepadAxis::LeftStickY),
+    )
+}
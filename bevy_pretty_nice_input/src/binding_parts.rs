@@ -1,8 +1,14 @@
-use bevy::input::gamepad::GamepadAxisChangedEvent;
+use bevy::ecs::query::QueryData;
+use bevy::ecs::system::SystemParam;
+use bevy::input::gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent};
 use bevy::input::keyboard::KeyboardInput;
-use bevy::input::mouse::{MouseButtonInput, MouseMotion, MouseWheel};
+use bevy::input::mouse::{AccumulatedMouseMotion, MouseButtonInput, MouseWheel};
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 
+use crate::actions::AnyActionOf;
+use crate::bindings::BindingOf;
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
 #[relationship_target(relationship = BindingPartOf, linked_spawn)]
@@ -26,6 +32,196 @@ pub struct BindingPartUpdate {
     pub value: f32,
 }
 
+/// Description of a single binding part, for reading and replacing bindings at runtime.
+///
+/// Each variant matches a function in [`binding1d`](crate::binding1d). See [`Rebind`](crate::prelude::Rebind).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Clone, Debug, PartialEq, Hash)]
+pub enum BindingPart {
+    Key(KeyCode),
+    KeyAxis {
+        pos: KeyCode,
+        neg: KeyCode,
+    },
+    GamepadAxis(bevy::prelude::GamepadAxis),
+    GamepadButton(bevy::prelude::GamepadButton),
+    GamepadButtonAxis {
+        pos: bevy::prelude::GamepadButton,
+        neg: bevy::prelude::GamepadButton,
+    },
+    MouseButton(bevy::prelude::MouseButton),
+    MouseMoveAxis(AxisDirection),
+    MouseScroll(MouseScrollDirection),
+    MouseScrollAxis(AxisDirection),
+}
+
+impl BindingPart {
+    pub fn name(&self) -> String {
+        match self {
+            BindingPart::Key(key) => format!("Key {:?}", key),
+            BindingPart::KeyAxis { pos, neg } => format!("Key Axis {:?} / {:?}", pos, neg),
+            BindingPart::GamepadAxis(axis) => format!("Gamepad Axis {:?}", axis),
+            BindingPart::GamepadButton(button) => format!("Gamepad Button {:?}", button),
+            BindingPart::GamepadButtonAxis { pos, neg } => {
+                format!("Gamepad Button Axis {:?} / {:?}", pos, neg)
+            }
+            BindingPart::MouseButton(button) => format!("Mouse Button {:?}", button),
+            BindingPart::MouseMoveAxis(axis) => format!("Mouse Move Axis {:?}", axis),
+            BindingPart::MouseScroll(direction) => format!("Mouse Scroll {:?}", direction),
+            BindingPart::MouseScrollAxis(axis) => format!("Mouse Scroll Axis {:?}", axis),
+        }
+    }
+
+    /// Whether the two binding parts read any of the same physical inputs, like [`BindingPart::Key`] and a
+    /// [`BindingPart::KeyAxis`] using the same key.
+    pub fn overlaps(&self, other: &BindingPart) -> bool {
+        let inputs = self.physical_inputs();
+        other
+            .physical_inputs()
+            .iter()
+            .any(|input| inputs.contains(input))
+    }
+
+    fn physical_inputs(&self) -> Vec<PhysicalInput> {
+        match *self {
+            BindingPart::Key(key) => vec![PhysicalInput::Key(key)],
+            BindingPart::KeyAxis { pos, neg } => {
+                vec![PhysicalInput::Key(pos), PhysicalInput::Key(neg)]
+            }
+            BindingPart::GamepadAxis(axis) => vec![PhysicalInput::GamepadAxis(axis)],
+            BindingPart::GamepadButton(button) => vec![PhysicalInput::GamepadButton(button)],
+            BindingPart::GamepadButtonAxis { pos, neg } => vec![
+                PhysicalInput::GamepadButton(pos),
+                PhysicalInput::GamepadButton(neg),
+            ],
+            BindingPart::MouseButton(button) => vec![PhysicalInput::MouseButton(button)],
+            BindingPart::MouseMoveAxis(axis) => vec![PhysicalInput::MouseMove(axis)],
+            BindingPart::MouseScroll(direction) => vec![PhysicalInput::MouseScroll(direction)],
+            BindingPart::MouseScrollAxis(AxisDirection::X) => vec![
+                PhysicalInput::MouseScroll(MouseScrollDirection::Right),
+                PhysicalInput::MouseScroll(MouseScrollDirection::Left),
+            ],
+            BindingPart::MouseScrollAxis(AxisDirection::Y) => vec![
+                PhysicalInput::MouseScroll(MouseScrollDirection::Up),
+                PhysicalInput::MouseScroll(MouseScrollDirection::Down),
+            ],
+        }
+    }
+
+    /// This binding part with `from` swapped out for `to`, or `None` if that would change what kind of binding part it is.
+    ///
+    /// Axes made of two keys or buttons only swap the matching side, so the other side is kept.
+    pub(crate) fn swapped(self, from: BindingPart, to: BindingPart) -> Option<BindingPart> {
+        fn swap<T: PartialEq>(input: T, from: T, to: T) -> T {
+            if input == from { to } else { input }
+        }
+
+        match (self, from, to) {
+            (BindingPart::KeyAxis { pos, neg }, BindingPart::Key(from), BindingPart::Key(to)) => {
+                Some(BindingPart::KeyAxis {
+                    pos: swap(pos, from, to),
+                    neg: swap(neg, from, to),
+                })
+            }
+            (
+                BindingPart::GamepadButtonAxis { pos, neg },
+                BindingPart::GamepadButton(from),
+                BindingPart::GamepadButton(to),
+            ) => Some(BindingPart::GamepadButtonAxis {
+                pos: swap(pos, from, to),
+                neg: swap(neg, from, to),
+            }),
+            _ if std::mem::discriminant(&self) == std::mem::discriminant(&to) => Some(to),
+            _ => None,
+        }
+    }
+
+    /// Turns the entity into this binding part, replacing whichever one it was and resetting its data.
+    pub(crate) fn insert_into(self, entity: &mut EntityWorldMut) {
+        entity.remove::<(
+            Key,
+            KeyAxis,
+            GamepadAxis,
+            GamepadButton,
+            GamepadButtonAxis,
+            MouseButton,
+            MouseMoveAxis,
+            MouseScroll,
+            MouseScrollAxis,
+        )>();
+        entity.insert((Name::new(self.name()), BindingPartData::default()));
+        match self {
+            BindingPart::Key(key) => entity.insert(Key(key)),
+            BindingPart::KeyAxis { pos, neg } => entity.insert(KeyAxis::new(pos, neg)),
+            BindingPart::GamepadAxis(axis) => entity.insert(GamepadAxis(axis)),
+            BindingPart::GamepadButton(button) => entity.insert(GamepadButton(button)),
+            BindingPart::GamepadButtonAxis { pos, neg } => {
+                entity.insert(GamepadButtonAxis::new(pos, neg))
+            }
+            BindingPart::MouseButton(button) => entity.insert(MouseButton(button)),
+            BindingPart::MouseMoveAxis(axis) => entity.insert(MouseMoveAxis(axis)),
+            BindingPart::MouseScroll(direction) => entity.insert(MouseScroll(direction)),
+            BindingPart::MouseScrollAxis(axis) => entity.insert(MouseScrollAxis(axis)),
+        };
+    }
+}
+
+/// A single key, button or axis that binding parts read from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PhysicalInput {
+    Key(KeyCode),
+    GamepadAxis(bevy::prelude::GamepadAxis),
+    GamepadButton(bevy::prelude::GamepadButton),
+    MouseButton(bevy::prelude::MouseButton),
+    MouseMove(AxisDirection),
+    MouseScroll(MouseScrollDirection),
+}
+
+/// Reads which [`BindingPart`] a binding part entity is.
+#[derive(QueryData)]
+pub(crate) struct BindingPartQuery {
+    key: Option<&'static Key>,
+    key_axis: Option<&'static KeyAxis>,
+    gamepad_axis: Option<&'static GamepadAxis>,
+    gamepad_button: Option<&'static GamepadButton>,
+    gamepad_button_axis: Option<&'static GamepadButtonAxis>,
+    mouse_button: Option<&'static MouseButton>,
+    mouse_move_axis: Option<&'static MouseMoveAxis>,
+    mouse_scroll: Option<&'static MouseScroll>,
+    mouse_scroll_axis: Option<&'static MouseScrollAxis>,
+}
+
+impl BindingPartQueryItem<'_, '_> {
+    pub(crate) fn binding_part(&self) -> Option<BindingPart> {
+        if let Some(key) = self.key {
+            Some(BindingPart::Key(key.0))
+        } else if let Some(key_axis) = self.key_axis {
+            Some(BindingPart::KeyAxis {
+                pos: key_axis.pos.key,
+                neg: key_axis.neg.key,
+            })
+        } else if let Some(gamepad_axis) = self.gamepad_axis {
+            Some(BindingPart::GamepadAxis(gamepad_axis.0))
+        } else if let Some(gamepad_button) = self.gamepad_button {
+            Some(BindingPart::GamepadButton(gamepad_button.0))
+        } else if let Some(button_axis) = self.gamepad_button_axis {
+            Some(BindingPart::GamepadButtonAxis {
+                pos: button_axis.pos,
+                neg: button_axis.neg,
+            })
+        } else if let Some(mouse_button) = self.mouse_button {
+            Some(BindingPart::MouseButton(mouse_button.0))
+        } else if let Some(mouse_move_axis) = self.mouse_move_axis {
+            Some(BindingPart::MouseMoveAxis(mouse_move_axis.0))
+        } else if let Some(mouse_scroll) = self.mouse_scroll {
+            Some(BindingPart::MouseScroll(mouse_scroll.0))
+        } else {
+            self.mouse_scroll_axis
+                .map(|mouse_scroll_axis| BindingPart::MouseScrollAxis(mouse_scroll_axis.0))
+        }
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
 pub struct Key(pub KeyCode);
@@ -124,19 +320,172 @@ pub fn binding_part_key_axis(
     }
 }
 
+/// Component for input system entities that decides which gamepads their gamepad bindings listen to.
+///
+/// Input system entities without this component listen to every gamepad.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// fn spawn_player(gamepad: Entity, mut commands: Commands) {
+///     commands.spawn((
+///         OwnedGamepad::Only(gamepad),
+///         input!(Jump, Axis1D[binding1d::south()]),
+///     ));
+/// }
+/// ```
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Clone, Default, Debug, PartialEq)]
+pub enum OwnedGamepad {
+    /// Listen to every gamepad.
+    #[default]
+    Any,
+    /// Only listen to the gamepad with this entity.
+    Only(Entity),
+}
+
+impl OwnedGamepad {
+    pub fn accepts(&self, gamepad: Entity) -> bool {
+        match self {
+            OwnedGamepad::Any => true,
+            OwnedGamepad::Only(owned) => *owned == gamepad,
+        }
+    }
+}
+
+/// Walks from a binding up to its input system entity to check its [`OwnedGamepad`].
+#[derive(SystemParam)]
+pub struct GamepadOwnership<'w, 's> {
+    bindings: Query<'w, 's, &'static BindingOf>,
+    actions: Query<'w, 's, &'static AnyActionOf>,
+    inputs: Query<'w, 's, &'static OwnedGamepad>,
+}
+
+impl GamepadOwnership<'_, '_> {
+    pub fn accepts(&self, binding: Entity, gamepad: Entity) -> bool {
+        let Ok(binding_of) = self.bindings.get(binding) else {
+            return true;
+        };
+        let Ok(action_of) = self.actions.get(binding_of.0) else {
+            return true;
+        };
+        self.inputs
+            .get(action_of.0)
+            .map(|owned| owned.accepts(gamepad))
+            .unwrap_or(true)
+    }
+}
+
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
 pub struct GamepadAxis(pub bevy::prelude::GamepadAxis);
 
 pub fn binding_part_gamepad_axis(
     mut binding_parts: Query<(Entity, &GamepadAxis, &BindingPartOf, &mut BindingPartData)>,
+    ownership: GamepadOwnership,
     mut commands: Commands,
     mut gamepad_axis: MessageReader<GamepadAxisChangedEvent>,
 ) {
     for message in gamepad_axis.read() {
         for (entity, gamepad_axis, binding_part_of, mut data) in binding_parts.iter_mut() {
             let value = message.value;
-            if gamepad_axis.0 == message.axis && data.0 != value {
+            if gamepad_axis.0 == message.axis
+                && ownership.accepts(binding_part_of.0, message.entity)
+                && data.0 != value
+            {
+                data.0 = value;
+                commands.trigger(BindingPartUpdate {
+                    binding: binding_part_of.0,
+                    binding_part: entity,
+                    value,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct GamepadButton(pub bevy::prelude::GamepadButton);
+
+pub fn binding_part_gamepad_button(
+    mut binding_parts: Query<(Entity, &GamepadButton, &BindingPartOf, &mut BindingPartData)>,
+    ownership: GamepadOwnership,
+    mut commands: Commands,
+    mut gamepad_button: MessageReader<GamepadButtonChangedEvent>,
+) {
+    for message in gamepad_button.read() {
+        for (entity, gamepad_button, binding_part_of, mut data) in binding_parts.iter_mut() {
+            // Analog for triggers, 0 or 1 for everything else
+            let value = message.value;
+            if gamepad_button.0 == message.button
+                && ownership.accepts(binding_part_of.0, message.entity)
+                && data.0 != value
+            {
+                data.0 = value;
+                commands.trigger(BindingPartUpdate {
+                    binding: binding_part_of.0,
+                    binding_part: entity,
+                    value,
+                });
+            }
+        }
+    }
+}
+
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct GamepadButtonAxis {
+    pub pos: bevy::prelude::GamepadButton,
+    pub neg: bevy::prelude::GamepadButton,
+    pub pos_value: f32,
+    pub neg_value: f32,
+}
+
+impl GamepadButtonAxis {
+    pub fn new(
+        pos: bevy::prelude::GamepadButton,
+        neg: bevy::prelude::GamepadButton,
+    ) -> GamepadButtonAxis {
+        GamepadButtonAxis {
+            pos,
+            neg,
+            pos_value: 0.0,
+            neg_value: 0.0,
+        }
+    }
+}
+
+pub fn binding_part_gamepad_button_axis(
+    mut binding_parts: Query<(
+        Entity,
+        &mut GamepadButtonAxis,
+        &BindingPartOf,
+        &mut BindingPartData,
+    )>,
+    ownership: GamepadOwnership,
+    mut commands: Commands,
+    mut gamepad_button: MessageReader<GamepadButtonChangedEvent>,
+) {
+    for message in gamepad_button.read() {
+        for (entity, mut button_axis, binding_part_of, mut data) in binding_parts.iter_mut() {
+            if !ownership.accepts(binding_part_of.0, message.entity) {
+                continue;
+            }
+
+            if button_axis.pos == message.button {
+                button_axis.pos_value = message.value;
+            } else if button_axis.neg == message.button {
+                button_axis.neg_value = message.value;
+            } else {
+                continue;
+            }
+
+            let value = button_axis.pos_value - button_axis.neg_value;
+            if data.0 != value {
                 data.0 = value;
                 commands.trigger(BindingPartUpdate {
                     binding: binding_part_of.0,
@@ -172,8 +521,8 @@ pub fn binding_part_mouse_button(
     }
 }
 
-#[derive(Debug, Reflect)]
-#[reflect(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Clone, Debug, PartialEq, Hash)]
 pub enum AxisDirection {
     X,
     Y,
@@ -195,16 +544,19 @@ pub struct MouseMoveAxis(pub AxisDirection);
 pub fn binding_part_mouse_move(
     mut binding_parts: Query<(Entity, &MouseMoveAxis, &BindingPartOf, &mut BindingPartData)>,
     mut commands: Commands,
-    mut mouse: MessageReader<MouseMotion>,
+    mouse: Res<AccumulatedMouseMotion>,
 ) {
-    for message in mouse.read() {
-        for (entity, mouse_move, binding_part_of, mut data) in binding_parts.iter_mut() {
-            let value = match mouse_move.0 {
-                AxisDirection::X => message.delta.x,
-                AxisDirection::Y => message.delta.y,
-            };
-            if data.0 != value {
-                data.0 = value;
+    // Every part's data is set before any update is handled, so one update per binding carries the whole frame's motion
+    let mut updated = HashSet::new();
+    for (entity, mouse_move, binding_part_of, mut data) in binding_parts.iter_mut() {
+        let value = match mouse_move.0 {
+            AxisDirection::X => mouse.delta.x,
+            AxisDirection::Y => mouse.delta.y,
+        };
+        // Motion is a per-frame delta, so repeated values still need to be sent
+        if data.0 != value || value != 0.0 {
+            data.0 = value;
+            if updated.insert(binding_part_of.0) {
                 commands.trigger(BindingPartUpdate {
                     binding: binding_part_of.0,
                     binding_part: entity,
@@ -215,8 +567,8 @@ pub fn binding_part_mouse_move(
     }
 }
 
-#[derive(Debug, Reflect)]
-#[reflect(Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Clone, Debug, PartialEq, Hash)]
 pub enum MouseScrollDirection {
     Up,
     Down,
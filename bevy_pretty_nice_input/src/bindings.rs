@@ -19,3 +19,75 @@ pub struct BindingUpdate {
     pub action: Entity,
     pub data: ActionData,
 }
+
+/// The current value of a single binding, before it's combined with the action's other bindings.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct BindingData {
+    pub data: ActionData,
+    /// Goes up every time the binding goes from zero to nonzero, for [`BindingAggregation::MostRecent`].
+    pub(crate) pressed_order: u64,
+}
+
+impl BindingData {
+    pub fn new(data: ActionData) -> Self {
+        Self {
+            data,
+            pressed_order: 0,
+        }
+    }
+}
+
+/// How an action with multiple bindings combines their values into one.
+///
+/// Set this with `aggregation = ...` in [`input!`](crate::prelude::input). Defaults to [`BindingAggregation::LargestMagnitude`].
+#[derive(Component, Clone, Copy, Default, Debug, PartialEq, Eq, Reflect)]
+#[reflect(Component, Clone, Default, Debug, PartialEq)]
+pub enum BindingAggregation {
+    /// Uses the binding with the largest [length](ActionData::length), preferring earlier bindings on ties.
+    #[default]
+    LargestMagnitude,
+    /// Adds all the bindings together, then clamps each axis to \[-1,1\].
+    SumClamped,
+    /// Uses the binding that most recently went from zero to nonzero, falling back to the next most recent when it's released.
+    MostRecent,
+    /// Uses the first nonzero binding, in the order they're listed.
+    FirstNonzero,
+}
+
+impl BindingAggregation {
+    /// Combines the bindings' data, or returns [`None`] if there are no bindings.
+    pub fn aggregate<'a>(
+        &self,
+        bindings: impl IntoIterator<Item = &'a BindingData>,
+    ) -> Option<ActionData> {
+        let mut bindings = bindings.into_iter().peekable();
+        let zero = bindings.peek()?.data.zeroed();
+        let data = match self {
+            BindingAggregation::LargestMagnitude => {
+                bindings.map(|binding| binding.data).reduce(|best, data| {
+                    if data.length() > best.length() {
+                        data
+                    } else {
+                        best
+                    }
+                })
+            }
+            BindingAggregation::SumClamped => {
+                let sum = bindings
+                    .map(|binding| binding.data.axes())
+                    .sum::<Vec3>()
+                    .clamp(Vec3::NEG_ONE, Vec3::ONE);
+                Some(zero.with_axes(sum))
+            }
+            BindingAggregation::MostRecent => bindings
+                .filter(|binding| !binding.data.is_zero())
+                .max_by_key(|binding| binding.pressed_order)
+                .map(|binding| binding.data),
+            BindingAggregation::FirstNonzero => bindings
+                .map(|binding| binding.data)
+                .find(|data| !data.is_zero()),
+        };
+        Some(data.unwrap_or(zero))
+    }
+}
@@ -2,12 +2,14 @@
 
 #![expect(unsafe_code, reason = "Unsafe code is used to improve performance.")]
 
+use std::any::TypeId;
 use std::marker::PhantomData;
 
 use bevy::ecs::bundle::DynamicBundle;
 use bevy::ecs::component::{ComponentId, Components, ComponentsRegistrator, StorageType};
-use bevy::ecs::schedule::ScheduleLabel;
+use bevy::ecs::schedule::{InternedScheduleLabel, ScheduleConfigs, ScheduleLabel};
 use bevy::ecs::system::{IntoObserverSystem, ScheduleSystem};
+use bevy::platform::collections::HashSet;
 use bevy::prelude::*;
 use bevy::ptr::{MovingPtr, OwningPtr};
 
@@ -146,3 +148,193 @@ pub fn add_systems<M, I: IntoScheduleConfigs<ScheduleSystem, M>, S: ScheduleLabe
         marker: PhantomData,
     }
 }
+
+/// Helper struct that adds systems when inserted as a [`Bundle`], but only the first time for its key.
+///
+/// The systems are made by a function, since configured systems can't be sent between threads.
+pub struct AddSystemsOnce<S: ScheduleLabel> {
+    key: TypeId,
+    schedule: S,
+    systems: fn() -> ScheduleConfigs<ScheduleSystem>,
+}
+
+/// Keys of the [`AddSystemsOnce`] that have already added their systems.
+#[derive(Resource, Default)]
+struct AddedSystems(HashSet<TypeId>);
+
+// SAFETY: Empty method bodies.
+unsafe impl<S: ScheduleLabel> Bundle for AddSystemsOnce<S> {
+    #[inline]
+    fn component_ids(
+        _components: &mut ComponentsRegistrator,
+    ) -> impl Iterator<Item = ComponentId> + use<S> {
+        // SAFETY: Empty iterator
+        core::iter::empty()
+    }
+
+    #[inline]
+    fn get_component_ids(_components: &Components) -> impl Iterator<Item = Option<ComponentId>> {
+        // SAFETY: Empty iterator
+        core::iter::empty()
+    }
+}
+
+impl<S: ScheduleLabel> DynamicBundle for AddSystemsOnce<S> {
+    type Effect = Self;
+
+    #[inline]
+    unsafe fn get_components(
+        ptr: MovingPtr<'_, Self>,
+        _func: &mut impl FnMut(StorageType, OwningPtr<'_>),
+    ) {
+        // Forget the pointer so that the value is available in `apply_effect`.
+        std::mem::forget(ptr);
+    }
+
+    #[inline]
+    unsafe fn apply_effect(
+        ptr: MovingPtr<'_, core::mem::MaybeUninit<Self>>,
+        entity: &mut EntityWorldMut,
+    ) {
+        let add_systems = unsafe { ptr.assume_init() };
+        let add_systems = add_systems.read();
+        entity.world_scope(|world| {
+            add_systems_once_to_world(
+                world,
+                add_systems.key,
+                add_systems.schedule,
+                add_systems.systems,
+            );
+        });
+    }
+}
+
+/// Adds the systems to the world, unless systems with the same key were already added.
+pub(crate) fn add_systems_once_to_world(
+    world: &mut World,
+    key: TypeId,
+    schedule: impl ScheduleLabel,
+    systems: fn() -> ScheduleConfigs<ScheduleSystem>,
+) {
+    if !world.get_resource_or_init::<AddedSystems>().0.insert(key) {
+        return;
+    }
+    let schedule = schedule.intern();
+    if world
+        .try_schedule_scope(schedule, |_world, schedule| {
+            schedule.add_systems(systems());
+        })
+        .is_err()
+    {
+        // The schedule is taken out of the world while it runs, so this has to wait until it's done
+        world
+            .get_resource_or_init::<PendingSystems>()
+            .0
+            .push((schedule, systems));
+    }
+}
+
+/// Systems from [`add_systems_once_to_world`] whose schedule was running when they were added.
+#[derive(Resource, Default)]
+struct PendingSystems(
+    Vec<(
+        InternedScheduleLabel,
+        fn() -> ScheduleConfigs<ScheduleSystem>,
+    )>,
+);
+
+/// Adds the [`PendingSystems`] whose schedules aren't running anymore.
+pub(crate) fn add_pending_systems(world: &mut World) {
+    let Some(mut pending) = world.remove_resource::<PendingSystems>() else {
+        return;
+    };
+    pending.0.retain(|(schedule, systems)| {
+        world
+            .try_schedule_scope(*schedule, |_world, schedule| {
+                schedule.add_systems(systems());
+            })
+            .is_err()
+    });
+    world.insert_resource(pending);
+}
+
+/// Adds systems as a bundle effect, but only the first time one with the key `K` is inserted.
+pub fn add_systems_once<K: 'static, S: ScheduleLabel>(
+    schedule: S,
+    systems: fn() -> ScheduleConfigs<ScheduleSystem>,
+) -> AddSystemsOnce<S> {
+    AddSystemsOnce {
+        key: TypeId::of::<K>(),
+        schedule,
+        systems,
+    }
+}
+
+/// Helper struct that adds a global observer when inserted as a [`Bundle`], but only the first time for its key.
+///
+/// The observer is made by a function, like the systems of [`AddSystemsOnce`].
+pub struct AddObserverOnce {
+    key: TypeId,
+    observer: fn() -> Observer,
+}
+
+/// Keys of the [`AddObserverOnce`] that have already added their observers.
+#[derive(Resource, Default)]
+struct AddedObservers(HashSet<TypeId>);
+
+// SAFETY: Empty method bodies.
+unsafe impl Bundle for AddObserverOnce {
+    #[inline]
+    fn component_ids(
+        _components: &mut ComponentsRegistrator,
+    ) -> impl Iterator<Item = ComponentId> + use<> {
+        // SAFETY: Empty iterator
+        core::iter::empty()
+    }
+
+    #[inline]
+    fn get_component_ids(_components: &Components) -> impl Iterator<Item = Option<ComponentId>> {
+        // SAFETY: Empty iterator
+        core::iter::empty()
+    }
+}
+
+impl DynamicBundle for AddObserverOnce {
+    type Effect = Self;
+
+    #[inline]
+    unsafe fn get_components(
+        ptr: MovingPtr<'_, Self>,
+        _func: &mut impl FnMut(StorageType, OwningPtr<'_>),
+    ) {
+        // Forget the pointer so that the value is available in `apply_effect`.
+        std::mem::forget(ptr);
+    }
+
+    #[inline]
+    unsafe fn apply_effect(
+        ptr: MovingPtr<'_, core::mem::MaybeUninit<Self>>,
+        entity: &mut EntityWorldMut,
+    ) {
+        let add_observer = unsafe { ptr.assume_init() };
+        let add_observer = add_observer.read();
+        entity.world_scope(|world| {
+            add_observer_once_to_world(world, add_observer.key, add_observer.observer);
+        });
+    }
+}
+
+/// Spawns the observer, unless an observer with the same key was already spawned.
+fn add_observer_once_to_world(world: &mut World, key: TypeId, observer: fn() -> Observer) {
+    if world.get_resource_or_init::<AddedObservers>().0.insert(key) {
+        world.spawn(observer());
+    }
+}
+
+/// Adds a global observer as a bundle effect, but only the first time one with the key `K` is inserted.
+pub fn add_observer_once<K: 'static>(observer: fn() -> Observer) -> AddObserverOnce {
+    AddObserverOnce {
+        key: TypeId::of::<K>(),
+        observer,
+    }
+}
@@ -1,9 +1,15 @@
 use std::marker::PhantomData;
+use std::time::Duration;
 
-use crate::actions::{Action, ActionData};
+use crate::ConditionTickSystems;
+use crate::actions::{Action, ActionData, Actions, AnyActionOf, PrevAction2Data};
 use crate::bevy_event_chain::*;
-use crate::bundles::{add_systems, observe};
+use crate::bundles::{add_systems, add_systems_once, observe};
+use crate::events::{HoldProgress, JustPressed, JustReleased};
+use crate::predicates::InputPredicate;
+use crate::profile::ActionSettings;
 
+use bevy::ecs::component::Mutable;
 use bevy::ecs::query::QueryFilter;
 use bevy::prelude::*;
 
@@ -55,81 +61,279 @@ pub fn invalidate_pass(invalidate: On<InvalidateData>, mut commands: Commands) {
     invalidate.trigger_next(&mut commands);
 }
 
-/// [`Condition`] that only lets one valid input pass every duration.
-#[derive(Component, Debug, Reflect)]
-#[reflect(Component, Debug)]
-pub struct Cooldown {
-    timer: Timer,
-    prev: Option<ConditionedBindingUpdate>,
+/// Simpler way to write a [`Condition`] that keeps state. Anything implementing this is a [`Condition`].
+///
+/// The observers, previous data, invalidation, and tick system are all handled automatically.
+///
+/// This example only lets the input pass if it hasn't been pressed harder than `max` before:
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Component)]
+/// struct Gentle {
+///     max: f32,
+///     broken: bool,
+/// }
+///
+/// impl StatefulCondition for Gentle {
+///     fn on_update(&mut self, data: ActionData, _context: &ConditionContext) -> Outcome {
+///         self.broken |= data.length() > self.max;
+///         if self.broken { Outcome::Zero } else { Outcome::Pass }
+///     }
+///
+///     fn on_invalidate(&mut self) {
+///         self.broken = false;
+///     }
+/// }
+/// ```
+pub trait StatefulCondition: Component<Mutability = Mutable> + Sized {
+    /// Decides what to do with an update.
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome;
+
+    /// Resets any state when the input is invalidated. The previous data is already cleared.
+    fn on_invalidate(&mut self) {}
+
+    /// Runs every frame once there's been an update, where `context.prev` is the latest data.
+    /// The outcome is applied to the latest update.
+    fn on_tick(&mut self, _context: &ConditionContext) -> Outcome {
+        Outcome::Block
+    }
+
+    /// Runs when a [`ResetBuffer`] later in the chain lets a press through, once there's been an update.
+    /// The outcome is applied to the latest update.
+    fn on_reset_buffer(&mut self, _context: &ConditionContext) -> Outcome {
+        Outcome::Block
+    }
+
+    /// How far along the condition is, in \[0,1\], or `None` if it isn't in progress.
+    ///
+    /// Whenever this changes, [`HoldProgress`] is fired on the input system entity, with `None` as `0.0`.
+    fn progress(&self) -> Option<f32> {
+        None
+    }
 }
 
-impl Cooldown {
-    pub fn new(duration: f32) -> Self {
-        let mut timer = Timer::from_seconds(duration, TimerMode::Once);
-        timer.finish();
-        Self { timer, prev: None }
+/// What a [`StatefulCondition`] can see besides its own state.
+pub struct ConditionContext<'a> {
+    pub name: &'a Name,
+    /// The input system entity.
+    pub input: Entity,
+    /// The data from the previous update, if there was one since the last invalidation.
+    pub prev: Option<ActionData>,
+    pub time: &'a Time,
+}
+
+/// What a [`StatefulCondition`] does with an update.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Outcome {
+    /// Passes the update along unchanged.
+    Pass,
+    /// Passes the update along with different data.
+    Replace(ActionData),
+    /// Passes the update along with zeroed data.
+    Zero,
+    /// Discards the update.
+    Block,
+    /// [Invalidates](InvalidateData) the input.
+    Invalidate,
+    /// Passes the update along once for each data, in order.
+    Many(Vec<ActionData>),
+}
+
+impl Outcome {
+    fn apply(self, update: &ConditionedBindingUpdate, commands: &mut Commands) {
+        match self {
+            Outcome::Pass => update.trigger_next(commands),
+            Outcome::Replace(data) => update.trigger_next_with_data(data, commands),
+            Outcome::Zero => update.trigger_next_with_data(update.data.zeroed(), commands),
+            Outcome::Block => {}
+            Outcome::Invalidate => InvalidateData::from(update).trigger_next(commands),
+            Outcome::Many(datas) => {
+                for data in datas {
+                    update.trigger_next_with_data(data, commands);
+                }
+            }
+        }
+    }
+}
+
+/// The latest update a [`StatefulCondition`] for the action `A` received, and the progress it last reported.
+#[derive(Component)]
+pub struct LastUpdate<A: Action> {
+    update: Option<ConditionedBindingUpdate>,
+    progress: Option<f32>,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Action> Default for LastUpdate<A> {
+    fn default() -> Self {
+        Self {
+            update: None,
+            progress: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Action> LastUpdate<A> {
+    /// Fires [`HoldProgress`] if the condition's progress changed since it was last reported.
+    fn report_progress(&mut self, progress: Option<f32>, input: Entity, commands: &mut Commands) {
+        if progress == self.progress {
+            return;
+        }
+        self.progress = progress;
+        commands.trigger(HoldProgress::<A> {
+            input,
+            progress: progress.unwrap_or(0.0),
+            _marker: PhantomData,
+        });
     }
 }
 
-impl Condition for Cooldown {
+impl<T: StatefulCondition> Condition for T {
     fn bundle<A: Action>(&self) -> impl Bundle {
         (
-            observe(
-                |update: On<ConditionedBindingUpdate>,
-                 mut conditions: Query<(&Name, &mut Cooldown)>,
-                 mut commands: Commands|
-                 -> Result {
-                    let (name, mut condition) = conditions.get_mut(update.event_target())?;
-
-                    let data = update.data;
-                    let prev_data = condition
-                        .prev
-                        .replace(update.clone())
-                        .map(|prev| prev.data)
-                        .unwrap_or(data);
-
-                    if !data.is_zero() && prev_data.is_zero() {
-                        if condition.timer.is_finished() {
-                            debug!("{} Cooling down", name);
-                            update.trigger_next(&mut commands);
-                            update.trigger_next_with_data(data.zeroed(), &mut commands);
-                        } else {
-                            debug!("{} Re-cooling down", name);
-                        }
-                        condition.timer.set_mode(TimerMode::Repeating);
-                    } else if data.is_zero() {
-                        debug!("{} Un-cooling down", name);
-                        condition.timer.set_mode(TimerMode::Once);
-                        update.trigger_next(&mut commands);
-                    }
-                    Ok(())
-                },
-            ),
-            observe(
-                |invalidate: On<InvalidateData>, mut conditions: Query<&mut Cooldown>| -> Result {
-                    let mut condition = conditions.get_mut(invalidate.event_target())?;
-                    condition.prev = None;
-                    Ok(())
-                },
-            ),
+            LastUpdate::<A>::default(),
+            observe(stateful_update::<T, A>),
+            observe(stateful_invalidate::<T, A>),
+            observe(stateful_reset_buffer::<T, A>),
+            add_systems_once::<(T, A), _>(PreUpdate, || {
+                tick_stateful::<T, A>.in_set(ConditionTickSystems)
+            }),
         )
     }
 }
 
-pub fn tick_cooldown(
-    mut conditions: Query<(&Name, &mut Cooldown)>,
+fn stateful_update<T: StatefulCondition, A: Action>(
+    update: On<ConditionedBindingUpdate>,
+    mut conditions: Query<(&Name, &mut T, &mut LastUpdate<A>)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) -> Result {
+    let (name, mut condition, mut last) = conditions.get_mut(update.event_target())?;
+    let context = ConditionContext {
+        name,
+        input: update.input,
+        prev: last.update.as_ref().map(|prev| prev.data),
+        time: &time,
+    };
+    let outcome = condition.on_update(update.data, &context);
+    last.update = Some(update.clone());
+    outcome.apply(&update, &mut commands);
+    last.report_progress(condition.progress(), update.input, &mut commands);
+    Ok(())
+}
+
+fn stateful_invalidate<T: StatefulCondition, A: Action>(
+    invalidate: On<InvalidateData>,
+    mut conditions: Query<(&mut T, &mut LastUpdate<A>)>,
+    mut commands: Commands,
+) -> Result {
+    let (mut condition, mut last) = conditions.get_mut(invalidate.event_target())?;
+    let prev = last.update.take();
+    condition.on_invalidate();
+    if let Some(prev) = prev {
+        last.report_progress(condition.progress(), prev.input, &mut commands);
+    }
+    Ok(())
+}
+
+fn stateful_reset_buffer<T: StatefulCondition, A: Action>(
+    reset: On<ResetBufferEvent>,
+    mut conditions: Query<(&Name, &mut T, &mut LastUpdate<A>)>,
+    time: Res<Time>,
+    mut commands: Commands,
+) -> Result {
+    let (name, mut condition, mut last) = conditions.get_mut(reset.event_target())?;
+    let Some(update) = last.update.clone() else {
+        return Ok(());
+    };
+    let context = ConditionContext {
+        name,
+        input: update.input,
+        prev: Some(update.data),
+        time: &time,
+    };
+    condition
+        .on_reset_buffer(&context)
+        .apply(&update, &mut commands);
+    last.report_progress(condition.progress(), update.input, &mut commands);
+    Ok(())
+}
+
+fn tick_stateful<T: StatefulCondition, A: Action>(
+    mut conditions: Query<(&Name, &mut T, &mut LastUpdate<A>)>,
     time: Res<Time>,
     mut commands: Commands,
 ) {
-    for (name, mut condition) in conditions.iter_mut() {
-        condition.timer.tick(time.delta());
-        if condition.timer.is_finished()
-            && condition.timer.mode() == TimerMode::Repeating
-            && let Some(prev) = &condition.prev
-        {
-            debug!("{} Cooldown finished, sending {:?}", name, prev.data);
-            prev.trigger_next(&mut commands);
-            prev.trigger_next_with_data(prev.data.zeroed(), &mut commands);
+    for (name, mut condition, mut last) in conditions.iter_mut() {
+        let Some(update) = last.update.clone() else {
+            continue;
+        };
+        let context = ConditionContext {
+            name,
+            input: update.input,
+            prev: Some(update.data),
+            time: &time,
+        };
+        condition.on_tick(&context).apply(&update, &mut commands);
+        last.report_progress(condition.progress(), update.input, &mut commands);
+    }
+}
+
+/// [`Condition`] that only lets one valid input pass every duration.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct Cooldown {
+    duration: Duration,
+    ready_at: Duration,
+    held: bool,
+}
+
+impl Cooldown {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            duration: Duration::from_secs_f32(duration),
+            ready_at: Duration::ZERO,
+            held: false,
+        }
+    }
+}
+
+impl StatefulCondition for Cooldown {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
+        let now = context.time.elapsed();
+
+        if !data.is_zero() && prev_data.is_zero() {
+            self.held = true;
+            if now >= self.ready_at {
+                debug!("{} Cooling down", context.name);
+                self.ready_at = now + self.duration;
+                Outcome::Many(vec![data, data.zeroed()])
+            } else {
+                debug!("{} Re-cooling down", context.name);
+                Outcome::Block
+            }
+        } else if data.is_zero() {
+            debug!("{} Un-cooling down", context.name);
+            self.held = false;
+            Outcome::Pass
+        } else {
+            Outcome::Block
+        }
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        let now = context.time.elapsed();
+        match context.prev {
+            Some(data) if self.held && now >= self.ready_at => {
+                debug!("{} Cooldown finished, sending {:?}", context.name, data);
+                self.ready_at = now + self.duration;
+                Outcome::Many(vec![data, data.zeroed()])
+            }
+            _ => Outcome::Block,
         }
     }
 }
@@ -208,68 +412,130 @@ impl<F: QueryFilter + Send + Sync + 'static> Condition for InvalidatingFilter<F>
     }
 }
 
+/// [`Condition`] that only lets the input pass if the [`InputPredicate`] passes. Otherwise, zeroes the input, or [invalidates](InvalidateData) it if [`When::invalidating`] is set.
+///
+/// Unlike [`Filter`], predicates can check the whole world, such as states and resources.
+/// See [`predicates`](crate::predicates) for the available ones.
+///
+/// Predicates run in a queued command, so this leaves the chain of updates that otherwise happens all at once.
+/// Its action's [`JustPressed`] and [`Updated`](crate::prelude::Updated) fire after those of actions updated later in the same flush,
+/// and an [`InvalidateData`] from another condition can overtake its update.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// use bevy_pretty_nice_input::predicates::{AllOf, InState, Not, ResourceExists};
+///
+/// # #[derive(Action)]
+/// # struct Jump;
+/// #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[derive(Resource)]
+/// struct Paused;
+///
+/// input!(
+///     Jump,
+///     Axis1D[binding1d::space()],
+///     [When::new(AllOf((
+///         InState(GameState::Playing),
+///         Not(ResourceExists::<Paused>::default()),
+///     )))]
+/// )
+/// # ;
+/// ```
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct When<P: InputPredicate> {
+    pub invalidating: bool,
+    #[reflect(ignore)]
+    predicate: Option<P>,
+}
+
+impl<P: InputPredicate> When<P> {
+    pub fn new(predicate: P) -> Self {
+        Self {
+            invalidating: false,
+            predicate: Some(predicate),
+        }
+    }
+
+    pub fn invalidating(mut self) -> Self {
+        self.invalidating = true;
+        self
+    }
+}
+
+impl<P: InputPredicate> Condition for When<P> {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        observe(
+            |update: On<ConditionedBindingUpdate>, mut commands: Commands| {
+                let update = update.clone();
+                // Predicates need the whole world, which observers can't have
+                commands.queue(move |world: &mut World| -> Result {
+                    let condition = update.event_target();
+                    let mut predicate = world
+                        .get_mut::<When<P>>(condition)
+                        .and_then(|mut when| when.predicate.take())
+                        .ok_or(BevyError::from("When condition is missing its predicate"))?;
+                    let passed = predicate.test(&update, world);
+                    let mut when = world
+                        .get_mut::<When<P>>(condition)
+                        .ok_or(BevyError::from("When condition was removed"))?;
+                    when.predicate = Some(predicate);
+                    let invalidating = when.invalidating;
+
+                    let mut commands = world.commands();
+                    if passed {
+                        update.trigger_next(&mut commands);
+                    } else if invalidating {
+                        InvalidateData::from(&update).trigger_next(&mut commands);
+                    } else {
+                        update.trigger_next_with_data(update.data.zeroed(), &mut commands);
+                    }
+                    Ok(())
+                });
+            },
+        )
+    }
+}
+
 /// [`Condition`] that acts as a rising edge filter.
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
 pub struct ButtonPress {
     pub threshold: f32,
-    prev: Option<ActionData>,
 }
 
 impl ButtonPress {
     pub fn new(threshold: f32) -> Self {
-        Self {
-            threshold,
-            prev: None,
-        }
+        Self { threshold }
     }
 }
 
 impl Default for ButtonPress {
     fn default() -> Self {
-        Self {
-            threshold: 0.5,
-            prev: None,
-        }
+        Self { threshold: 0.5 }
     }
 }
 
-impl Condition for ButtonPress {
-    fn bundle<A: Action>(&self) -> impl Bundle {
-        (
-            observe(
-                |update: On<ConditionedBindingUpdate>,
-                 mut commands: Commands,
-                 mut conditions: Query<(&Name, &mut ButtonPress)>|
-                 -> Result {
-                    let (name, mut condition) = conditions.get_mut(update.event_target())?;
-
-                    let data = update.data;
-                    let prev_data = condition.prev.replace(update.data).unwrap_or(data);
+impl StatefulCondition for ButtonPress {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
 
-                    if data.is_pressed_with(condition.threshold)
-                        && !prev_data.is_pressed_with(condition.threshold)
-                    {
-                        debug!("{} Button Pressed", name);
-                        update.trigger_next(&mut commands);
-                        update.trigger_next_with_data(data.zeroed(), &mut commands);
-                    } else if !data.is_pressed_with(condition.threshold) {
-                        debug!("{} Button Passed", name);
-                        update.trigger_next_with_data(data.zeroed(), &mut commands);
-                    }
-                    Ok(())
-                },
-            ),
-            observe(
-                |invalidate: On<InvalidateData>,
-                 mut conditions: Query<&mut ButtonPress>|
-                 -> Result {
-                    let mut condition = conditions.get_mut(invalidate.event_target())?;
-                    condition.prev = None;
-                    Ok(())
-                },
-            ),
-        )
+        if data.is_pressed_with(self.threshold) && !prev_data.is_pressed_with(self.threshold) {
+            debug!("{} Button Pressed", context.name);
+            Outcome::Many(vec![data, data.zeroed()])
+        } else if !data.is_pressed_with(self.threshold) {
+            debug!("{} Button Passed", context.name);
+            Outcome::Zero
+        } else {
+            Outcome::Block
+        }
     }
 }
 
@@ -278,196 +544,1013 @@ impl Condition for ButtonPress {
 #[reflect(Component, Debug)]
 pub struct ButtonRelease {
     pub threshold: f32,
-    prev: Option<ActionData>,
 }
 
 impl ButtonRelease {
     pub fn new(threshold: f32) -> Self {
-        Self {
-            threshold,
-            prev: None,
-        }
+        Self { threshold }
     }
 }
 
 impl Default for ButtonRelease {
     fn default() -> Self {
-        Self {
-            threshold: 0.5,
-            prev: None,
-        }
+        Self { threshold: 0.5 }
     }
 }
 
-impl Condition for ButtonRelease {
-    fn bundle<A: Action>(&self) -> impl Bundle {
-        (
-            observe(
-                |update: On<ConditionedBindingUpdate>,
-                 mut commands: Commands,
-                 mut conditions: Query<&mut ButtonRelease>|
-                 -> Result {
-                    let mut condition = conditions.get_mut(update.event_target())?;
-
-                    let data = update.data;
-                    let prev_data = condition.prev.replace(update.data).unwrap_or(data);
+impl StatefulCondition for ButtonRelease {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
 
-                    if !data.is_pressed_with(condition.threshold)
-                        && prev_data.is_pressed_with(condition.threshold)
-                    {
-                        update.trigger_next_with_data(prev_data, &mut commands);
-                        update.trigger_next(&mut commands);
-                    }
-                    Ok(())
-                },
-            ),
-            observe(
-                |invalidate: On<InvalidateData>,
-                 mut conditions: Query<&mut ButtonRelease>|
-                 -> Result {
-                    let mut condition = conditions.get_mut(invalidate.event_target())?;
-                    condition.prev = None;
-                    Ok(())
-                },
-            ),
-        )
+        if !data.is_pressed_with(self.threshold) && prev_data.is_pressed_with(self.threshold) {
+            Outcome::Many(vec![prev_data, data])
+        } else {
+            Outcome::Block
+        }
     }
 }
 
-/// [`Condition`] that inverts the update between zero and nonzero, using the last nonzero input when the current input is zero.
+/// [`Condition`] that sends a press and release when the input is let go, but only if it was pressed for less than `max_duration`.
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
-pub struct Invert {
-    prev_nonzero: Option<ActionData>,
+pub struct Tap {
+    pub threshold: f32,
+    timer: Timer,
+    pressing: bool,
+    pressed_data: Option<ActionData>,
 }
 
-impl Condition for Invert {
-    fn bundle<A: Action>(&self) -> impl Bundle {
-        observe(
-            |update: On<ConditionedBindingUpdate>,
-             mut commands: Commands,
-             mut conditions: Query<&mut Invert>|
-             -> Result {
-                let mut condition = conditions.get_mut(update.event_target())?;
+impl Tap {
+    pub fn new(max_duration: f32) -> Self {
+        Self {
+            threshold: 0.5,
+            timer: Timer::from_seconds(max_duration, TimerMode::Once),
+            pressing: false,
+            pressed_data: None,
+        }
+    }
 
-                let data = update.data;
-                let prev_good = condition.prev_nonzero;
-                if !data.is_zero() {
-                    condition.prev_nonzero = Some(data);
-                }
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
 
-                if data.is_zero() {
-                    if let Some(prev) = prev_good {
-                        update.trigger_next_with_data(prev, &mut commands);
-                    } else {
-                        // No idea what to do if there's no previous good input. Perhaps a Binding::inverted_default()?
-                    }
-                } else {
-                    update.trigger_next_with_data(data.zeroed(), &mut commands);
+impl StatefulCondition for Tap {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
+
+        if data.is_pressed_with(self.threshold) && !prev_data.is_pressed_with(self.threshold) {
+            self.pressing = true;
+            self.pressed_data = Some(data);
+            self.timer.reset();
+            Outcome::Block
+        } else if !data.is_pressed_with(self.threshold) {
+            let tapped = self.pressing && !self.timer.is_finished();
+            self.pressing = false;
+            match self.pressed_data {
+                Some(pressed_data) if tapped => {
+                    debug!("{} Tapped", context.name);
+                    Outcome::Many(vec![pressed_data, data.zeroed()])
                 }
-                Ok(())
-            },
-        )
+                _ => Outcome::Zero,
+            }
+        } else {
+            Outcome::Block
+        }
+    }
+
+    fn on_invalidate(&mut self) {
+        self.pressing = false;
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        if self.pressing {
+            self.timer.tick(context.time.delta());
+        }
+        Outcome::Block
     }
 }
 
-/// [`Condition`] that continues sending nonzero updates for a duration after the input stops being nonzero.
+/// [`Condition`] that only lets the input pass on the `count`th press in a row, where each press is within `max_gap` seconds of the last.
+///
+/// The input keeps passing until it's let go, so this works for both double-tapping and double-tap-and-holding.
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component, Debug)]
-pub struct InputBuffer {
+pub struct MultiTap {
+    pub threshold: f32,
+    pub count: u32,
     timer: Timer,
-    prev: Option<ConditionedBindingUpdate>,
+    taps: u32,
+    active: bool,
 }
 
-impl InputBuffer {
-    pub fn new(duration: f32) -> Self {
-        let mut timer = Timer::from_seconds(duration, TimerMode::Once);
-        timer.finish();
-        Self { timer, prev: None }
+impl MultiTap {
+    pub fn new(count: u32, max_gap: f32) -> Self {
+        Self {
+            threshold: 0.5,
+            count,
+            timer: Timer::from_seconds(max_gap, TimerMode::Once),
+            taps: 0,
+            active: false,
+        }
     }
 
-    pub fn force_finish(&mut self) {
-        let was_paused = self.timer.is_paused();
-        self.timer.unpause();
-        self.timer.finish();
-        if was_paused {
-            self.timer.pause();
+    pub fn double(max_gap: f32) -> Self {
+        Self::new(2, max_gap)
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl StatefulCondition for MultiTap {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
+
+        if data.is_pressed_with(self.threshold) && !prev_data.is_pressed_with(self.threshold) {
+            if self.timer.is_finished() {
+                self.taps = 0;
+            }
+            self.taps += 1;
+            self.timer.reset();
+            debug!("{} Tap {}/{}", context.name, self.taps, self.count);
+            if self.taps >= self.count {
+                self.taps = 0;
+                self.active = true;
+                Outcome::Pass
+            } else {
+                Outcome::Block
+            }
+        } else if data.is_pressed_with(self.threshold) {
+            if self.active {
+                Outcome::Pass
+            } else {
+                Outcome::Block
+            }
+        } else {
+            self.active = false;
+            Outcome::Zero
+        }
+    }
+
+    fn on_invalidate(&mut self) {
+        self.taps = 0;
+        self.active = false;
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        if self.taps > 0 {
+            self.timer.tick(context.time.delta());
+        }
+        Outcome::Block
+    }
+}
+
+/// [`Condition`] that only lets the input pass when it's pressed right after a sequence of other actions on the same input system entity, such as for fighting game combos.
+///
+/// Each step is an [`Action`] that must be pressed within its window of the next step, and the last step within its window of this input.
+/// Up to `leniency` presses of steps that don't fit the sequence are ignored. The input keeps passing until it's let go.
+///
+/// Put an [`InputBuffer`] after this condition to buffer the combo.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # #[derive(Action)]
+/// # struct Down;
+/// # #[derive(Action)]
+/// # struct DownForward;
+/// # #[derive(Action)]
+/// # struct Forward;
+/// # #[derive(Action)]
+/// # struct Fireball;
+/// # fn spawn(mut commands: Commands) {
+/// commands.spawn(input!(
+///     Fireball,
+///     Axis1D[binding1d::key(KeyCode::KeyJ)],
+///     [Sequence::new()
+///         .step::<Down>(0.2)
+///         .step::<DownForward>(0.2)
+///         .step::<Forward>(0.2)
+///         .with_leniency(1)]
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct Sequence {
+    pub threshold: f32,
+    pub leniency: usize,
+    #[reflect(ignore)]
+    steps: Vec<SequenceStep>,
+    history: Vec<(usize, Duration)>,
+    active: bool,
+    prev: Option<ActionData>,
+}
+
+#[derive(Debug)]
+struct SequenceStep {
+    window: Duration,
+    observer: fn(usize) -> Observer,
+}
+
+impl Sequence {
+    pub fn new() -> Self {
+        Self {
+            threshold: 0.5,
+            leniency: 0,
+            steps: Vec::new(),
+            history: Vec::new(),
+            active: false,
+            prev: None,
+        }
+    }
+
+    /// Adds a step that must be pressed at most `window` seconds before the next step.
+    pub fn step<S: Action>(mut self, window: f32) -> Self {
+        self.steps.push(SequenceStep {
+            window: Duration::from_secs_f32(window),
+            observer: record_sequence_step::<S>,
+        });
+        self
+    }
+
+    pub fn with_leniency(mut self, leniency: usize) -> Self {
+        self.leniency = leniency;
+        self
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn matches(&self, now: Duration) -> bool {
+        let mut remaining = self.steps.len();
+        let mut next = now;
+        let mut skipped = 0;
+        for &(step, at) in self.history.iter().rev() {
+            if remaining == 0 {
+                break;
+            }
+            if step == remaining - 1 {
+                if next.saturating_sub(at) > self.steps[step].window {
+                    return false;
+                }
+                remaining -= 1;
+                next = at;
+            } else {
+                skipped += 1;
+                if skipped > self.leniency {
+                    return false;
+                }
+            }
         }
+        remaining == 0
+    }
+
+    fn record(&mut self, step: usize, at: Duration) {
+        let max_age: Duration = self.steps.iter().map(|step| step.window).sum();
+        self.history
+            .retain(|&(_, prev)| at.saturating_sub(prev) <= max_age);
+        self.history.push((step, at));
+    }
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Condition for InputBuffer {
+fn record_sequence_step<S: Action>(step: usize) -> Observer {
+    Observer::new(
+        move |pressed: On<JustPressed<S>>,
+              observers: Query<&ChildOf>,
+              mut conditions: Query<(&ConditionOf, &mut Sequence)>,
+              actions: Query<&AnyActionOf>,
+              time: Res<Time>|
+              -> Result {
+            let (condition_of, mut condition) =
+                conditions.get_mut(observers.get(pressed.observer())?.parent())?;
+            if actions.get(condition_of.0)?.0 == pressed.input {
+                condition.record(step, time.elapsed());
+            }
+            Ok(())
+        },
+    )
+}
+
+// Not a `StatefulCondition`, since it needs observers for the step actions
+impl Condition for Sequence {
     fn bundle<A: Action>(&self) -> impl Bundle {
+        let observers = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(i, step)| (step.observer)(i))
+            .collect::<Vec<_>>();
         (
             observe(
                 |update: On<ConditionedBindingUpdate>,
                  mut commands: Commands,
-                 mut conditions: Query<&mut InputBuffer>|
+                 mut conditions: Query<(&Name, &mut Sequence)>,
+                 time: Res<Time>|
                  -> Result {
-                    let mut condition = conditions.get_mut(update.event_target())?;
+                    let (name, mut condition) = conditions.get_mut(update.event_target())?;
 
                     let data = update.data;
+                    let prev_data = condition.prev.replace(data).unwrap_or(data);
 
-                    update.trigger_next(&mut commands);
-                    if !data.is_zero() {
-                        condition.prev = Some(update.clone());
-                        condition.timer.reset();
-                        condition.timer.pause();
+                    if data.is_pressed_with(condition.threshold)
+                        && !prev_data.is_pressed_with(condition.threshold)
+                    {
+                        if condition.matches(time.elapsed()) {
+                            debug!("{} Sequence matched", name);
+                            condition.history.clear();
+                            condition.active = true;
+                            update.trigger_next(&mut commands);
+                        }
+                    } else if data.is_pressed_with(condition.threshold) {
+                        if condition.active {
+                            update.trigger_next(&mut commands);
+                        }
                     } else {
-                        condition.timer.unpause();
+                        condition.active = false;
+                        update.trigger_next_with_data(data.zeroed(), &mut commands);
                     }
                     Ok(())
                 },
             ),
             observe(
-                |invalidate: On<InvalidateData>,
-                 mut conditions: Query<&mut InputBuffer>|
-                 -> Result {
+                |invalidate: On<InvalidateData>, mut conditions: Query<&mut Sequence>| -> Result {
                     let mut condition = conditions.get_mut(invalidate.event_target())?;
                     condition.prev = None;
-                    condition.force_finish();
+                    condition.history.clear();
+                    condition.active = false;
                     Ok(())
                 },
             ),
+            // Steps are pressed on the input system entity, so these need global observers
+            Children::spawn(SpawnIter(observers.into_iter())),
+        )
+    }
+}
+
+/// [`Condition`] that only lets the input pass while the action `B` on the same input system entity is pressed, such as for Ctrl+S.
+///
+/// `B` needs to be pressed first, and letting go of `B` also lets go of this input.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct Chord<B: Action> {
+    passing: bool,
+    prev: Option<ConditionedBindingUpdate>,
+    _marker: PhantomData<B>,
+}
+
+impl<B: Action> Default for Chord<B> {
+    fn default() -> Self {
+        Self {
+            passing: false,
+            prev: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+// Not a `StatefulCondition`, since it needs an observer for letting go of `B`
+impl<B: Action> Condition for Chord<B> {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        (
             observe(
-                |reset: On<ResetBufferEvent>,
+                |update: On<ConditionedBindingUpdate>,
                  mut commands: Commands,
-                 mut condition: Query<(&Name, &mut InputBuffer)>|
+                 mut conditions: Query<&mut Chord<B>>,
+                 inputs: Query<&Actions<B>>,
+                 actions: Query<&PrevAction2Data>|
                  -> Result {
-                    let (name, mut condition) = condition.get_mut(reset.event_target())?;
-                    debug!("Resetting {} input buffer", name);
-                    condition.force_finish();
-                    if let Some(prev) = &condition.prev {
+                    let mut condition = conditions.get_mut(update.event_target())?;
+
+                    let chord_pressed = inputs.get(update.input).is_ok_and(|chord_actions| {
+                        actions
+                            .iter_many(chord_actions.collection())
+                            .any(|prev| prev.0.is_some_and(|data| !data.is_zero()))
+                    });
+
+                    condition.prev = Some(update.clone());
+                    if chord_pressed && !update.data.is_zero() {
+                        condition.passing = true;
+                        update.trigger_next(&mut commands);
+                    } else {
+                        condition.passing = false;
+                        update.trigger_next_with_data(update.data.zeroed(), &mut commands);
+                    }
+                    Ok(())
+                },
+            ),
+            observe(
+                |invalidate: On<InvalidateData>, mut conditions: Query<&mut Chord<B>>| -> Result {
+                    let mut condition = conditions.get_mut(invalidate.event_target())?;
+                    condition.prev = None;
+                    condition.passing = false;
+                    Ok(())
+                },
+            ),
+            // The release happens on the input system entity, so this needs a global observer
+            Children::spawn(Spawn(Observer::new(
+                |released: On<JustReleased<B>>,
+                 observers: Query<&ChildOf>,
+                 mut conditions: Query<(&Name, &mut Chord<B>)>,
+                 mut commands: Commands|
+                 -> Result {
+                    let (name, mut condition) =
+                        conditions.get_mut(observers.get(released.observer())?.parent())?;
+                    if !condition.passing {
+                        return Ok(());
+                    }
+                    if let Some(prev) = &condition.prev
+                        && prev.input == released.input
+                    {
+                        debug!("{} Chord let go", name);
                         prev.trigger_next_with_data(prev.data.zeroed(), &mut commands);
+                        condition.passing = false;
                     }
                     Ok(())
                 },
+            ))),
+        )
+    }
+}
+
+/// [`Condition`] that turns each press into flipping the input on or off, for toggling instead of holding.
+///
+/// While the input system entity doesn't match the query filter, the input passes through unchanged,
+/// so inserting or removing a marker component switches between toggling and holding at runtime.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Component, Default)]
+/// struct Sprinting;
+///
+/// /// Inserted by the settings menu when sprint is set to toggle.
+/// #[derive(Component)]
+/// struct ToggleSprint;
+///
+/// input_transition!(
+///     () <=> (Sprinting),
+///     Axis1D[binding1d::left_shift()],
+///     [ToggleWhen::<With<ToggleSprint>>::default()]
+/// )
+/// # ;
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToggleWhen<F: QueryFilter> {
+    pub threshold: f32,
+    latch: ToggleLatch,
+    _marker: PhantomData<F>,
+}
+
+/// [`Condition`] that always toggles. See [`ToggleWhen`].
+pub type Toggle = ToggleWhen<()>;
+
+impl<F: QueryFilter> ToggleWhen<F> {
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl<F: QueryFilter> Default for ToggleWhen<F> {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            latch: ToggleLatch::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<F: QueryFilter + Send + Sync + 'static> Condition for ToggleWhen<F> {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        (
+            observe(
+                |update: On<ConditionedBindingUpdate>,
+                 mut commands: Commands,
+                 mut conditions: Query<(&Name, &mut ToggleWhen<F>)>,
+                 inputs: Query<(), F>|
+                 -> Result {
+                    let (name, mut condition) = conditions.get_mut(update.event_target())?;
+                    let toggling = inputs.get(update.input).is_ok();
+                    let threshold = condition.threshold;
+                    condition
+                        .latch
+                        .update(name, threshold, toggling, &update, &mut commands);
+                    Ok(())
+                },
+            ),
+            observe(
+                |invalidate: On<InvalidateData>,
+                 mut conditions: Query<&mut ToggleWhen<F>>|
+                 -> Result {
+                    let mut condition = conditions.get_mut(invalidate.event_target())?;
+                    condition.latch = ToggleLatch::default();
+                    Ok(())
+                },
             ),
         )
     }
 }
 
-pub fn tick_input_buffer(
-    mut conditions: Query<(&Name, &mut InputBuffer)>,
-    time: Res<Time>,
-    mut commands: Commands,
-) {
-    for (name, mut condition) in conditions.iter_mut() {
-        condition.timer.tick(time.delta());
-        if !condition.timer.is_finished()
-            && let Some(prev) = &condition.prev
-        {
-            debug!("{} Input Buffer active, sending {:?}", name, prev.data);
-            prev.trigger_next(&mut commands);
-        } else if condition.timer.just_finished()
-            && let Some(prev) = &condition.prev
-        {
-            debug!(
-                "{} Input Buffer finished, sending {:?}",
-                name,
-                prev.data.zeroed()
-            );
-            prev.trigger_next_with_data(prev.data.zeroed(), &mut commands);
+/// [`Condition`] that toggles like [`Toggle`] while the action's [`ActionSettings::toggle`] is set,
+/// and otherwise lets the input pass through, so players can choose between toggling and holding.
+///
+/// For [`input_transition!`](crate::prelude::input_transition)s, whose actions can't be named, use [`ToggleWhen`] instead.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Aim;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn((
+///         ActionSettings::<Aim>::default().with_toggle(true),
+///         input!(Aim, Axis1D[binding1d::mouse_button(MouseButton::Right)], [ToggleSetting::default()]),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ToggleSetting {
+    pub threshold: f32,
+    latch: ToggleLatch,
+}
+
+impl ToggleSetting {
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl Default for ToggleSetting {
+    fn default() -> Self {
+        Self {
+            threshold: 0.5,
+            latch: ToggleLatch::default(),
+        }
+    }
+}
+
+impl Condition for ToggleSetting {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        (
+            observe(
+                |update: On<ConditionedBindingUpdate>,
+                 mut commands: Commands,
+                 mut conditions: Query<(&Name, &mut ToggleSetting)>,
+                 settings: Query<&ActionSettings<A>>|
+                 -> Result {
+                    let (name, mut condition) = conditions.get_mut(update.event_target())?;
+                    let toggling = settings
+                        .get(update.input)
+                        .is_ok_and(|settings| settings.toggle);
+                    let threshold = condition.threshold;
+                    condition
+                        .latch
+                        .update(name, threshold, toggling, &update, &mut commands);
+                    Ok(())
+                },
+            ),
+            observe(
+                |invalidate: On<InvalidateData>,
+                 mut conditions: Query<&mut ToggleSetting>|
+                 -> Result {
+                    let mut condition = conditions.get_mut(invalidate.event_target())?;
+                    condition.latch = ToggleLatch::default();
+                    Ok(())
+                },
+            ),
+        )
+    }
+}
+
+/// State shared by [`ToggleWhen`] and [`ToggleSetting`].
+#[derive(Default, Debug, Reflect)]
+struct ToggleLatch {
+    latched: Option<ActionData>,
+    prev: Option<ActionData>,
+}
+
+impl ToggleLatch {
+    fn update(
+        &mut self,
+        name: &Name,
+        threshold: f32,
+        toggling: bool,
+        update: &ConditionedBindingUpdate,
+        commands: &mut Commands,
+    ) {
+        let data = update.data;
+        let prev_data = self.prev.replace(data).unwrap_or(data);
+        let pressed = data.is_pressed_with(threshold);
+
+        if !toggling {
+            self.latched = pressed.then_some(data);
+            update.trigger_next(commands);
+            return;
+        }
+
+        if pressed && !prev_data.is_pressed_with(threshold) {
+            self.latched = match self.latched {
+                Some(_) => None,
+                None => Some(data),
+            };
+            debug!("{} Toggled {}", name, self.latched.is_some());
+        } else if pressed && self.latched.is_some() {
+            self.latched = Some(data);
+        }
+
+        match self.latched {
+            Some(latched) => update.trigger_next_with_data(latched, commands),
+            None => update.trigger_next_with_data(data.zeroed(), commands),
+        }
+    }
+}
+
+/// [`Condition`] that changes the input with a function.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # #[derive(Action)]
+/// # struct Walk;
+/// // Stop diagonals from being faster
+/// input!(
+///     Walk,
+///     Axis2D[binding2d::wasd()],
+///     [Map::new(|data| data.with_axes(data.axes().clamp_length_max(1.0)))]
+/// )
+/// # ;
+/// ```
+#[derive(Component)]
+pub struct Map<F: FnMut(ActionData) -> ActionData + Send + Sync + 'static> {
+    map: F,
+}
+
+impl<F: FnMut(ActionData) -> ActionData + Send + Sync + 'static> Map<F> {
+    pub fn new(map: F) -> Self {
+        Self { map }
+    }
+}
+
+impl<F: FnMut(ActionData) -> ActionData + Send + Sync + 'static> Condition for Map<F> {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        observe(
+            |update: On<ConditionedBindingUpdate>,
+             mut conditions: Query<&mut Map<F>>,
+             mut commands: Commands|
+             -> Result {
+                let mut condition = conditions.get_mut(update.event_target())?;
+                let data = (condition.map)(update.data);
+                update.trigger_next_with_data(data, &mut commands);
+                Ok(())
+            },
+        )
+    }
+}
+
+/// What a [`MapWith`] function can see besides the input.
+pub struct MapContext<'a> {
+    pub time: &'a Time,
+    /// The input system entity.
+    pub input: EntityRef<'a>,
+}
+
+/// [`Condition`] that changes the input with a function that can also see the [`Time`] and the input system entity.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # #[derive(Action)]
+/// # struct Look;
+/// #[derive(Component)]
+/// struct Sensitivity(f32);
+///
+/// input!(
+///     Look,
+///     Axis2D[binding2d::mouse_move()],
+///     [MapWith::new(|data, context| {
+///         let sensitivity = context.input.get::<Sensitivity>().map_or(1.0, |s| s.0);
+///         data.with_axes(data.axes() * sensitivity)
+///     })]
+/// )
+/// # ;
+/// ```
+#[derive(Component)]
+pub struct MapWith<F: FnMut(ActionData, &MapContext) -> ActionData + Send + Sync + 'static> {
+    map: F,
+}
+
+impl<F: FnMut(ActionData, &MapContext) -> ActionData + Send + Sync + 'static> MapWith<F> {
+    pub fn new(map: F) -> Self {
+        Self { map }
+    }
+}
+
+impl<F: FnMut(ActionData, &MapContext) -> ActionData + Send + Sync + 'static> Condition
+    for MapWith<F>
+{
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        observe(
+            |update: On<ConditionedBindingUpdate>,
+             mut conditions: Query<&mut MapWith<F>>,
+             inputs: Query<EntityRef, Without<MapWith<F>>>,
+             time: Res<Time>,
+             mut commands: Commands|
+             -> Result {
+                let mut condition = conditions.get_mut(update.event_target())?;
+                let context = MapContext {
+                    time: &time,
+                    input: inputs.get(update.input)?,
+                };
+                let data = (condition.map)(update.data, &context);
+                update.trigger_next_with_data(data, &mut commands);
+                Ok(())
+            },
+        )
+    }
+}
+
+/// [`Condition`] that inverts the update between zero and nonzero, using the last nonzero input when the current input is zero.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct Invert {
+    prev_nonzero: Option<ActionData>,
+}
+
+impl Condition for Invert {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        observe(
+            |update: On<ConditionedBindingUpdate>,
+             mut commands: Commands,
+             mut conditions: Query<&mut Invert>|
+             -> Result {
+                let mut condition = conditions.get_mut(update.event_target())?;
+
+                let data = update.data;
+                let prev_good = condition.prev_nonzero;
+                if !data.is_zero() {
+                    condition.prev_nonzero = Some(data);
+                }
+
+                if data.is_zero() {
+                    if let Some(prev) = prev_good {
+                        update.trigger_next_with_data(prev, &mut commands);
+                    } else {
+                        // No idea what to do if there's no previous good input. Perhaps a Binding::inverted_default()?
+                    }
+                } else {
+                    update.trigger_next_with_data(data.zeroed(), &mut commands);
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+/// [`Condition`] that continues sending nonzero updates for a duration after the input stops being nonzero.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct InputBuffer {
+    timer: Timer,
+    buffered: Option<ActionData>,
+}
+
+impl InputBuffer {
+    pub fn new(duration: f32) -> Self {
+        let mut timer = Timer::from_seconds(duration, TimerMode::Once);
+        timer.finish();
+        Self {
+            timer,
+            buffered: None,
+        }
+    }
+
+    pub fn force_finish(&mut self) {
+        let was_paused = self.timer.is_paused();
+        self.timer.unpause();
+        self.timer.finish();
+        if was_paused {
+            self.timer.pause();
+        }
+    }
+}
+
+impl StatefulCondition for InputBuffer {
+    fn on_update(&mut self, data: ActionData, _context: &ConditionContext) -> Outcome {
+        if !data.is_zero() {
+            self.buffered = Some(data);
+            self.timer.reset();
+            self.timer.pause();
+        } else {
+            self.timer.unpause();
+        }
+        Outcome::Pass
+    }
+
+    fn on_invalidate(&mut self) {
+        self.buffered = None;
+        self.force_finish();
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        self.timer.tick(context.time.delta());
+        match self.buffered {
+            Some(buffered) if !self.timer.is_finished() => {
+                debug!(
+                    "{} Input Buffer active, sending {:?}",
+                    context.name, buffered
+                );
+                Outcome::Replace(buffered)
+            }
+            Some(buffered) if self.timer.just_finished() => {
+                debug!(
+                    "{} Input Buffer finished, sending {:?}",
+                    context.name,
+                    buffered.zeroed()
+                );
+                Outcome::Replace(buffered.zeroed())
+            }
+            _ => Outcome::Block,
+        }
+    }
+
+    fn on_reset_buffer(&mut self, context: &ConditionContext) -> Outcome {
+        debug!("Resetting {} input buffer", context.name);
+        self.force_finish();
+        match self.buffered {
+            Some(buffered) => Outcome::Replace(buffered.zeroed()),
+            None => Outcome::Block,
+        }
+    }
+}
+
+/// [`Condition`] that only lets the input pass once it's been held for a duration, optionally re-sending it periodically after that.
+///
+/// While held, its [progress](StatefulCondition::progress) is fired as [`HoldProgress`] on the input system entity.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct Hold {
+    pub threshold: f32,
+    timer: Timer,
+    repeat: Option<Timer>,
+    holding: bool,
+}
+
+impl Hold {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            threshold: 0.5,
+            timer: Timer::from_seconds(duration, TimerMode::Once),
+            repeat: None,
+            holding: false,
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// Re-sends the input as a new press every `interval` seconds once the hold has finished.
+    pub fn repeating(mut self, interval: f32) -> Self {
+        self.repeat = Some(Timer::from_seconds(interval, TimerMode::Repeating));
+        self
+    }
+}
+
+impl StatefulCondition for Hold {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let prev_data = context.prev.unwrap_or(data);
+
+        if data.is_pressed_with(self.threshold) && !prev_data.is_pressed_with(self.threshold) {
+            debug!("{} Hold started", context.name);
+            self.holding = true;
+            self.timer.reset();
+            if let Some(repeat) = &mut self.repeat {
+                repeat.reset();
+            }
+            Outcome::Block
+        } else if data.is_pressed_with(self.threshold) {
+            if self.holding && self.timer.is_finished() {
+                Outcome::Pass
+            } else {
+                Outcome::Block
+            }
+        } else {
+            if self.holding {
+                debug!("{} Hold let go", context.name);
+                self.holding = false;
+            }
+            Outcome::Zero
+        }
+    }
+
+    fn on_invalidate(&mut self) {
+        self.holding = false;
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        let Some(data) = context.prev.filter(|_| self.holding) else {
+            return Outcome::Block;
+        };
+
+        if !self.timer.is_finished() {
+            self.timer.tick(context.time.delta());
+            if self.timer.is_finished() {
+                debug!("{} Hold finished, sending {:?}", context.name, data);
+                return Outcome::Pass;
+            }
+        } else if let Some(repeat) = &mut self.repeat {
+            repeat.tick(context.time.delta());
+            if repeat.just_finished() {
+                debug!("{} Hold repeating, sending {:?}", context.name, data);
+                return Outcome::Many(vec![data.zeroed(), data]);
+            }
+        }
+        Outcome::Block
+    }
+
+    fn progress(&self) -> Option<f32> {
+        self.holding.then(|| self.timer.fraction())
+    }
+}
+
+/// [`Condition`] that re-sends the input as a new press while it's held, first after `initial_delay` seconds and then every `interval` seconds.
+///
+/// Useful for menu navigation, where holding a direction should keep moving the selection.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component, Debug)]
+pub struct Repeat {
+    pub threshold: f32,
+    initial_delay: Timer,
+    interval: Timer,
+}
+
+impl Repeat {
+    pub fn new(initial_delay: f32, interval: f32) -> Self {
+        Self {
+            threshold: 0.5,
+            initial_delay: Timer::from_seconds(initial_delay, TimerMode::Once),
+            interval: Timer::from_seconds(interval, TimerMode::Repeating),
+        }
+    }
+
+    pub fn with_threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+}
+
+impl StatefulCondition for Repeat {
+    fn on_update(&mut self, data: ActionData, context: &ConditionContext) -> Outcome {
+        let was_pressed = context
+            .prev
+            .is_some_and(|prev| prev.is_pressed_with(self.threshold));
+        if data.is_pressed_with(self.threshold) && !was_pressed {
+            self.initial_delay.reset();
+            self.interval.reset();
+        }
+        Outcome::Pass
+    }
+
+    fn on_tick(&mut self, context: &ConditionContext) -> Outcome {
+        let Some(data) = context.prev else {
+            return Outcome::Block;
+        };
+        if !data.is_pressed_with(self.threshold) {
+            return Outcome::Block;
+        }
+
+        let repeated = if !self.initial_delay.is_finished() {
+            self.initial_delay.tick(context.time.delta());
+            self.initial_delay.just_finished()
+        } else {
+            self.interval.tick(context.time.delta());
+            self.interval.just_finished()
+        };
+
+        if repeated {
+            debug!("{} Repeating, sending {:?}", context.name, data);
+            Outcome::Many(vec![data.zeroed(), data])
+        } else {
+            Outcome::Block
         }
     }
 }
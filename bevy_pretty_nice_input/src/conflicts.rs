@@ -0,0 +1,164 @@
+use bevy::ecs::system::SystemParam;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::actions::AnyActions;
+use crate::binding_parts::{BindingPart, BindingPartQuery, BindingParts};
+use crate::bindings::Bindings;
+
+/// A binding part of one of an input system entity's actions.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct BoundPart {
+    pub action: Entity,
+    /// Index of the binding in the action entity, which is what [`Rebind`](crate::prelude::Rebind) takes
+    /// unless the input system entity has more than one action entity of the same type.
+    pub binding: usize,
+    /// Index of the part in the binding.
+    pub index: usize,
+    /// The binding part entity.
+    pub entity: Entity,
+    pub part: BindingPart,
+}
+
+/// Two actions on the same input system entity that read the same physical input.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct BindingConflict {
+    pub first: BoundPart,
+    pub second: BoundPart,
+}
+
+/// Finds [`BindingConflict`]s between the actions of input system entities.
+///
+/// Bindings of the same action never conflict with each other. Chords and other actions that are meant to
+/// share inputs are reported too, so it's up to the game which conflicts matter.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn controls_menu(player: Single<Entity, With<Player>>, conflicts: BindingConflicts, names: Query<&Name>) {
+///     for conflict in conflicts.get(*player) {
+///         info!(
+///             "{} is used by both {:?} and {:?}",
+///             conflict.second.part.name(),
+///             names.get(conflict.first.action),
+///             names.get(conflict.second.action),
+///         );
+///     }
+/// }
+/// ```
+#[derive(SystemParam)]
+pub struct BindingConflicts<'w, 's> {
+    actions: Query<'w, 's, &'static AnyActions>,
+    bindings: Query<'w, 's, &'static Bindings>,
+    binding_parts: Query<'w, 's, &'static BindingParts>,
+    parts: Query<'w, 's, BindingPartQuery>,
+}
+
+impl BindingConflicts<'_, '_> {
+    /// Every binding part of every action on the input system entity.
+    pub fn bound_parts(&self, input: Entity) -> Vec<BoundPart> {
+        let mut result = Vec::new();
+        let Ok(actions) = self.actions.get(input) else {
+            return result;
+        };
+        for &action in actions.collection() {
+            let Ok(bindings) = self.bindings.get(action) else {
+                continue;
+            };
+            for (binding, &binding_entity) in bindings.collection().iter().enumerate() {
+                let Ok(binding_parts) = self.binding_parts.get(binding_entity) else {
+                    continue;
+                };
+                for (index, &entity) in binding_parts.collection().iter().enumerate() {
+                    if let Some(part) = self
+                        .parts
+                        .get(entity)
+                        .ok()
+                        .and_then(|part| part.binding_part())
+                    {
+                        result.push(BoundPart {
+                            action,
+                            binding,
+                            index,
+                            entity,
+                            part,
+                        });
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Every pair of binding parts from different actions on the input system entity that overlap.
+    pub fn get(&self, input: Entity) -> Vec<BindingConflict> {
+        let parts = self.bound_parts(input);
+        let mut result = Vec::new();
+        for (i, first) in parts.iter().enumerate() {
+            for second in &parts[i + 1..] {
+                if first.action != second.action && first.part.overlaps(&second.part) {
+                    result.push(BindingConflict {
+                        first: *first,
+                        second: *second,
+                    });
+                }
+            }
+        }
+        result
+    }
+
+    /// The binding parts on the input system entity that the binding part would conflict with,
+    /// like for checking a captured input before rebinding to it.
+    pub fn with(&self, input: Entity, part: BindingPart) -> Vec<BoundPart> {
+        self.bound_parts(input)
+            .into_iter()
+            .filter(|bound| bound.part.overlaps(&part))
+            .collect()
+    }
+}
+
+/// Optional system that warns about [`BindingConflict`]s once each, whether they were spawned or made by rebinding.
+///
+/// It checks every input system entity whenever it runs, so it's best kept to debug builds.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # let mut app = App::new();
+/// #[cfg(debug_assertions)]
+/// app.add_systems(Update, warn_binding_conflicts);
+/// ```
+pub fn warn_binding_conflicts(
+    inputs: Query<Entity, With<AnyActions>>,
+    conflicts: BindingConflicts,
+    names: Query<&Name>,
+    mut warned: Local<HashMap<Entity, Vec<BindingConflict>>>,
+) {
+    warned.retain(|input, _| inputs.contains(*input));
+    for input in inputs.iter() {
+        let found = conflicts.get(input);
+        let previous = warned.entry(input).or_default();
+        for conflict in found.iter().filter(|conflict| !previous.contains(conflict)) {
+            let name = |action| {
+                names
+                    .get(action)
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|_| action.to_string())
+            };
+            warn!(
+                "{} and {} on {} are both bound to {} and {}",
+                name(conflict.first.action),
+                name(conflict.second.action),
+                input,
+                conflict.first.part.name(),
+                conflict.second.part.name()
+            );
+        }
+        *previous = found;
+    }
+}
@@ -0,0 +1,319 @@
+use bevy::ecs::relationship::Relationship;
+use bevy::prelude::*;
+
+use crate::actions::{Action, ActionData, AnyActions, PrevActionData};
+use crate::bevy_event_chain::*;
+use crate::binding_parts::{
+    BindingPartOf, BindingParts, GamepadAxis, GamepadButton, GamepadButtonAxis, Key, KeyAxis,
+    MouseButton, MouseMoveAxis, MouseScroll, MouseScrollAxis,
+};
+use crate::bindings::{BindingData, BindingUpdate, Bindings};
+use crate::bundles::observe;
+use crate::conditions::{Condition, ConditionedBindingUpdate, Conditions, InvalidateData};
+use crate::conflicts::{BindingConflicts, BoundPart};
+
+/// A named group of actions on an input system entity, joined with the [`InContext`] condition.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub struct InputContext {
+    pub name: String,
+    /// Contexts with higher priorities consume inputs from lower ones.
+    pub priority: i32,
+    /// Actions in inactive contexts don't receive input.
+    pub active: bool,
+    /// Whether inputs bound in this context are kept from lower-priority contexts while it's active.
+    pub consume: bool,
+}
+
+impl InputContext {
+    /// An active context that consumes its inputs.
+    pub fn new(name: impl Into<String>, priority: i32) -> Self {
+        Self {
+            name: name.into(),
+            priority,
+            active: true,
+            consume: true,
+        }
+    }
+
+    pub fn with_active(mut self, active: bool) -> Self {
+        self.active = active;
+        self
+    }
+
+    pub fn with_consume(mut self, consume: bool) -> Self {
+        self.consume = consume;
+        self
+    }
+}
+
+/// Component for input system entities with the [`InputContext`]s that their actions can be in.
+///
+/// When an input is bound in an active context, actions in lower-priority contexts that read it are released
+/// and don't receive it until the context is deactivated. Actions without an [`InContext`] condition are never affected.
+///
+/// Changes take effect at the start of the next frame's input, so opening a menu from one action's events
+/// doesn't let the menu's actions see the same press.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Pause;
+///
+/// #[derive(Action)]
+/// struct CloseMenu;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands.spawn((
+///         InputContexts::new([
+///             InputContext::new("gameplay", 0),
+///             InputContext::new("menu", 10).with_active(false),
+///         ]),
+///         input!(Pause, Axis1D[binding1d::key(KeyCode::Escape)], [InContext::new("gameplay")]),
+///         input!(CloseMenu, Axis1D[binding1d::key(KeyCode::Escape)], [InContext::new("menu")]),
+///     ));
+/// }
+///
+/// fn open_menu(_pause: On<JustPressed<Pause>>, mut contexts: Single<&mut InputContexts>) {
+///     contexts.activate("menu");
+/// }
+/// ```
+#[derive(Component, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Component, Clone, Default, Debug, PartialEq)]
+pub struct InputContexts {
+    contexts: Vec<InputContext>,
+    /// What the contexts were at the start of this frame's input, which conditions go by
+    /// so that changes from one action's events don't affect other actions in the same frame.
+    applied: Vec<InputContext>,
+    /// Every binding part of the input system entity's actions, cached alongside `applied`.
+    bound: Vec<BoundPart>,
+}
+
+impl InputContexts {
+    pub fn new(contexts: impl IntoIterator<Item = InputContext>) -> Self {
+        let contexts = contexts.into_iter().collect::<Vec<_>>();
+        Self {
+            applied: contexts.clone(),
+            contexts,
+            bound: Vec::new(),
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &InputContext> {
+        self.contexts.iter()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&InputContext> {
+        self.contexts.iter().find(|context| context.name == name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut InputContext> {
+        self.contexts
+            .iter_mut()
+            .find(|context| context.name == name)
+    }
+
+    /// Adds a context, replacing any with the same name.
+    pub fn insert(&mut self, context: InputContext) {
+        match self.get_mut(&context.name) {
+            Some(existing) => *existing = context,
+            None => self.contexts.push(context),
+        }
+    }
+
+    /// Whether the context exists and is active.
+    pub fn is_active(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|context| context.active)
+    }
+
+    /// Activates the context, if it exists.
+    pub fn activate(&mut self, name: &str) {
+        self.set_active(name, true);
+    }
+
+    /// Deactivates the context, if it exists.
+    pub fn deactivate(&mut self, name: &str) {
+        self.set_active(name, false);
+    }
+
+    pub fn set_active(&mut self, name: &str, active: bool) {
+        if let Some(context) = self.get_mut(name) {
+            context.active = active;
+        }
+    }
+
+    fn applied(&self, name: &str) -> Option<&InputContext> {
+        self.applied.iter().find(|context| context.name == name)
+    }
+}
+
+/// [`Condition`] that puts the action in an [`InputContext`] of its input system entity's [`InputContexts`].
+///
+/// The input only passes while the context is active, and while none of the action's pressed binding parts
+/// are consumed by a higher-priority context. Contexts that don't exist count as inactive.
+///
+/// An input that was already held when the action became able to receive it has to be pressed again,
+/// so the key that opens a menu doesn't also press the menu's action bound to it.
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Clone, Debug)]
+pub struct InContext {
+    pub context: String,
+    passing: bool,
+    prev: Option<ActionData>,
+}
+
+impl InContext {
+    pub fn new(context: impl Into<String>) -> Self {
+        Self {
+            context: context.into(),
+            passing: false,
+            prev: None,
+        }
+    }
+}
+
+impl Condition for InContext {
+    fn bundle<A: Action>(&self) -> impl Bundle {
+        (
+            observe(
+                |update: On<ConditionedBindingUpdate>,
+                 mut commands: Commands,
+                 mut in_contexts: Query<&mut InContext>,
+                 inputs: Query<&InputContexts>,
+                 conditions: Query<&Conditions>,
+                 binding_parts: Query<&BindingPartOf>,
+                 bindings: Query<&BindingData>|
+                 -> Result {
+                    let allowed = {
+                        let name = &in_contexts.get(update.event_target())?.context;
+                        let contexts = inputs.get(update.input).ok();
+                        match contexts.and_then(|contexts| contexts.applied(name)) {
+                            Some(context) if context.active => {
+                                // Which context each action is in, if any
+                                let context_of = |action: Entity| {
+                                    conditions
+                                        .get(action)
+                                        .ok()?
+                                        .collection()
+                                        .iter()
+                                        .find_map(|&condition| in_contexts.get(condition).ok())
+                                        .and_then(|in_context| {
+                                            contexts?.applied(&in_context.context)
+                                        })
+                                };
+
+                                let bound = contexts.map_or(&[][..], |contexts| &contexts.bound);
+                                let consumed = bound
+                                    .iter()
+                                    .filter(|other| {
+                                        context_of(other.action).is_some_and(|other| {
+                                            other.active
+                                                && other.consume
+                                                && other.priority > context.priority
+                                        })
+                                    })
+                                    .map(|other| other.part)
+                                    .collect::<Vec<_>>();
+                                let pressed = bound
+                                    .iter()
+                                    .filter(|part| part.action == update.action)
+                                    .filter(|part| {
+                                        // Binding data is set from the part updates that led to this one,
+                                        // unlike binding part data, which scroll resets as soon as it's sent
+                                        binding_parts
+                                            .get(part.entity)
+                                            .and_then(|binding_part_of| {
+                                                bindings.get(binding_part_of.get())
+                                            })
+                                            .is_ok_and(|binding| {
+                                                binding.data.axes()[part.index] != 0.0
+                                            })
+                                    })
+                                    .collect::<Vec<_>>();
+
+                                pressed.is_empty()
+                                    || !pressed.iter().all(|part| {
+                                        consumed.iter().any(|other| other.overlaps(&part.part))
+                                    })
+                            }
+                            _ => false,
+                        }
+                    };
+
+                    let mut condition = in_contexts.get_mut(update.event_target())?;
+                    let data = update.data;
+                    let fresh = condition
+                        .prev
+                        .replace(data)
+                        .is_none_or(|prev| prev.is_zero());
+                    if data.is_zero() || !allowed {
+                        condition.passing = false;
+                        update.trigger_next_with_data(data.zeroed(), &mut commands);
+                    } else if condition.passing || fresh {
+                        condition.passing = true;
+                        update.trigger_next(&mut commands);
+                    } else {
+                        update.trigger_next_with_data(data.zeroed(), &mut commands);
+                    }
+                    Ok(())
+                },
+            ),
+            observe(
+                |invalidate: On<InvalidateData>, mut conditions: Query<&mut InContext>| -> Result {
+                    let mut condition = conditions.get_mut(invalidate.event_target())?;
+                    condition.passing = false;
+                    condition.prev = None;
+                    Ok(())
+                },
+            ),
+        )
+    }
+}
+
+/// Binding part entities that were just spawned or rebound, or bindings that were added or removed.
+type BindingsChanged = Or<(
+    Changed<AnyActions>,
+    Changed<Bindings>,
+    Changed<BindingParts>,
+    Added<Key>,
+    Added<KeyAxis>,
+    Added<GamepadAxis>,
+    Added<GamepadButton>,
+    Added<GamepadButtonAxis>,
+    Added<MouseButton>,
+    Added<MouseMoveAxis>,
+    Added<MouseScroll>,
+    Added<MouseScrollAxis>,
+)>;
+
+/// Re-sends every action's last input when contexts change, so actions that were held are released or pressed again.
+///
+/// Also caches the input system entity's bound parts whenever its bindings might have changed.
+pub(crate) fn refresh_input_contexts(
+    mut inputs: Query<(Entity, Mut<InputContexts>, &AnyActions)>,
+    actions: Query<&PrevActionData>,
+    bindings_changed: Query<(), BindingsChanged>,
+    bound: BindingConflicts,
+    mut commands: Commands,
+) {
+    let bindings_changed = !bindings_changed.is_empty();
+    for (input, mut contexts, any_actions) in inputs.iter_mut() {
+        if bindings_changed || contexts.is_added() {
+            contexts.bypass_change_detection().bound = bound.bound_parts(input);
+        }
+        if !contexts.is_changed() || contexts.applied == contexts.contexts {
+            continue;
+        }
+        let contexts = contexts.bypass_change_detection();
+        contexts.applied = contexts.contexts.clone();
+        for &action in any_actions.collection() {
+            if let Ok(prev) = actions.get(action) {
+                commands.trigger(BindingUpdate {
+                    action,
+                    data: prev.0,
+                });
+            }
+        }
+    }
+}
@@ -6,40 +6,51 @@ use bevy::prelude::*;
 use bevy_event_chain::*;
 pub use bevy_pretty_nice_input_derive::{Action, input, input_transition};
 
-pub use crate::actions::{Action, ActionData, ActionOf, Actions, PrevAction2Data, PrevActionData};
+use crate::action_state::ActionState;
+use crate::profile::ActionSettings;
+
+pub use crate::actions::{
+    Action, ActionData, ActionOf, ActionOutput, Actions, AnyActionOf, AnyActions, PrevAction2Data,
+    PrevActionData,
+};
 pub use crate::binding_parts::{BindingPartData, BindingPartUpdate, BindingParts};
-pub use crate::bindings::{BindingOf, BindingUpdate, Bindings};
+pub use crate::bindings::{BindingAggregation, BindingData, BindingOf, BindingUpdate, Bindings};
 pub use crate::conditions::{
     Condition, ConditionedBindingUpdate, Conditions, InputDisabled, InvalidateData, invalidate_pass,
 };
+pub use crate::dimension::{BindingList, OutputDimension};
 pub use crate::events::{JustPressed, JustReleased, Pressed, Updated};
+pub use crate::modifiers::Modifiers;
 
 struct BindingPartUpdateOrData<'a> {
     binding_part_index: usize,
     update_value: f32,
     binding_parts: Box<dyn Fn(Entity) -> Result<f32> + 'a>,
+    modify: Box<dyn Fn(Entity, f32) -> f32 + 'a>,
     binding_parts_rel: &'a BindingParts,
 }
 
 impl BindingPartUpdateOrData<'_> {
     fn get(&self, index: usize) -> Result<f32> {
-        if index == self.binding_part_index {
-            Ok(self.update_value)
+        let binding_part = self.binding_parts_rel.collection()[index];
+        let value = if index == self.binding_part_index {
+            self.update_value
         } else {
-            Ok((self.binding_parts)(
-                self.binding_parts_rel.collection()[index],
-            )?)
-        }
+            (self.binding_parts)(binding_part)?
+        };
+        Ok((self.modify)(binding_part, value))
     }
 }
 
 pub fn binding(
     update: On<BindingPartUpdate>,
-    bindings: Query<(&BindingOf, &BindingParts)>,
-    binding_parts: Query<&BindingPartData>,
+    bindings: Query<(&BindingOf, &BindingParts, Option<&Modifiers>)>,
+    binding_parts: Query<(&BindingPartData, Option<&Modifiers>)>,
+    mut binding_data: Query<&mut BindingData>,
+    actions: Query<(&Bindings, &BindingAggregation)>,
     mut commands: Commands,
 ) -> Result {
-    let (binding_of, binding_parts_rel) = bindings.get(update.binding)?;
+    let (binding_of, binding_parts_rel, modifiers) = bindings.get(update.binding)?;
 
     let binding_part_index = binding_parts_rel
         .collection()
@@ -49,7 +60,11 @@ pub fn binding(
     let update_or_data = BindingPartUpdateOrData {
         binding_part_index,
         update_value: update.value,
-        binding_parts: Box::new(|entity| Ok(binding_parts.get(entity)?.0)),
+        binding_parts: Box::new(|entity| Ok(binding_parts.get(entity)?.0.0)),
+        modify: Box::new(|entity, value| match binding_parts.get(entity) {
+            Ok((_, Some(modifiers))) => modifiers.apply(ActionData::Axis1D(value)).axes().x,
+            _ => value,
+        }),
         binding_parts_rel,
     };
 
@@ -70,8 +85,26 @@ pub fn binding(
         )));
     };
 
+    let data = modifiers.map_or(data, |modifiers| modifiers.apply(data));
+
     // debug!("Binding update received {:?}, {:?}", update.value, data);
 
+    let (siblings, aggregation) = actions.get(binding_of.0)?;
+    let last_pressed_order = binding_data
+        .iter_many(siblings.collection())
+        .map(|sibling| sibling.pressed_order)
+        .max()
+        .unwrap_or_default();
+    let mut current = binding_data.get_mut(update.binding)?;
+    if current.data.is_zero() && !data.is_zero() {
+        current.pressed_order = last_pressed_order + 1;
+    }
+    current.data = data;
+
+    let data = aggregation
+        .aggregate(binding_data.iter_many(siblings.collection()))
+        .ok_or(BevyError::from("Action has no bindings"))?;
+
     commands.trigger(BindingUpdate {
         action: binding_of.0,
         data,
@@ -117,13 +150,23 @@ pub fn action<A: Action>(
 pub fn action_2<A: Action>(
     update: On<ConditionedBindingUpdate>,
     mut actions: Query<(&ActionOf<A>, &mut PrevAction2Data)>,
+    mut states: Query<&mut ActionState<A>>,
+    settings: Query<&ActionSettings<A>>,
+    time: Res<Time>,
     mut commands: Commands,
 ) -> Result {
     let (action_of, mut prev) = actions.get_mut(update.action)?;
     let input = action_of.0;
 
-    let data = update.data;
-    let Some(prev_data) = prev.0.replace(data) else {
+    let data = match settings.get(input) {
+        Ok(settings) => settings.apply(update.data),
+        Err(_) => update.data,
+    };
+    let prev_data = prev.0.replace(data);
+    if let Ok(mut state) = states.get_mut(input) {
+        state.update(data, prev_data, time.elapsed());
+    }
+    let Some(prev_data) = prev_data else {
         debug!("Initialized {} with {:?}", ShortName::of::<A>(), data);
         return Ok(());
     };
@@ -136,26 +179,28 @@ pub fn action_2<A: Action>(
         );
     }
 
+    let output = A::Output::from_action_data(data);
     if !data.is_zero() && prev_data.is_zero() {
         if data.as_1d().is_some() {
             debug!("Action just pressed {}", ShortName::of::<A>());
         }
         commands.trigger(JustPressed::<A> {
             input,
-            data,
+            data: output,
             _marker: PhantomData,
         });
     }
     if !data.is_zero() {
         commands.trigger(Pressed::<A> {
             input,
-            data,
+            data: output,
             _marker: PhantomData,
         });
     }
     commands.trigger(Updated::<A> {
         input,
-        data,
+        data: output,
+        raw: data,
         _marker: PhantomData,
     });
     if data.is_zero() && !prev_data.is_zero() {
@@ -220,6 +265,16 @@ pub fn action_enable<A: Action>(
     Ok(())
 }
 
+/// Fails to compile if the [`Action`]'s output can't be made from bindings of dimension `D`.
+pub fn assert_output_dimension<A: Action, D>()
+where
+    A::Output: OutputDimension<D>,
+{
+}
+
+/// Fails to compile if the binding isn't of dimension `D`.
+pub fn assert_binding_dimension<D, L: BindingList<D>>(_binding: &L) {}
+
 pub fn transition_on<A: Action, F: Bundle, T: Bundle + Default>(
     pressed: On<JustPressed<A>>,
     mut commands: Commands,
@@ -257,10 +312,10 @@ pub fn transition_target<A: Action, T: Component + TryFrom<ActionData, Error = B
     debug!(
         "Transition updating {} with {:?}",
         ShortName::of::<T>(),
-        updated.data
+        updated.raw
     );
     commands
         .entity(updated.input)
-        .insert(T::try_from(updated.data)?);
+        .insert(T::try_from(updated.raw)?);
     Ok(())
 }
@@ -0,0 +1,58 @@
+//! Marker types for the binding dimensions in [`input!`](crate::prelude::input), and the traits checked against them at compile time.
+
+use bevy::ecs::spawn::SpawnableList;
+use bevy::prelude::*;
+
+use crate::actions::ActionData;
+use crate::binding_parts::BindingPartOf;
+use crate::modifiers::WithModifiers;
+
+pub struct Axis1D;
+pub struct Axis2D;
+pub struct Axis3D;
+
+/// Which binding dimensions an [`ActionOutput`](crate::prelude::ActionOutput) can be made from.
+#[diagnostic::on_unimplemented(
+    message = "an action with `{Self}` output can't be bound with `{D}` bindings",
+    label = "this action's output doesn't match the binding dimension"
+)]
+pub trait OutputDimension<D> {}
+
+impl<D> OutputDimension<D> for ActionData {}
+impl<D> OutputDimension<D> for bool {}
+impl OutputDimension<Axis1D> for f32 {}
+impl OutputDimension<Axis2D> for Vec2 {}
+impl OutputDimension<Axis3D> for Vec3 {}
+
+/// Binding parts that make up a binding of dimension `D`, such as the ones returned from [`binding1d`](crate::binding1d) and [`binding2d`](crate::binding2d).
+///
+/// A single part is 1-dimensional, and tuples of two or three 1-dimensional bindings are 2- or 3-dimensional.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// use bevy_pretty_nice_input::dimension::{Axis2D, BindingList};
+///
+/// fn ijkl() -> impl BindingList<Axis2D> {
+///     (
+///         binding1d::key_axis(KeyCode::KeyL, KeyCode::KeyJ),
+///         binding1d::key_axis(KeyCode::KeyI, KeyCode::KeyK),
+///     )
+/// }
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` isn't a `{D}` binding",
+    label = "this binding doesn't match the dimension of the `input!`"
+)]
+pub trait BindingList<D>: SpawnableList<BindingPartOf> {}
+
+impl<B: Bundle> BindingList<Axis1D> for Spawn<B> {}
+
+impl<X: BindingList<Axis1D>, Y: BindingList<Axis1D>> BindingList<Axis2D> for (X, Y) {}
+
+impl<X: BindingList<Axis1D>, Y: BindingList<Axis1D>, Z: BindingList<Axis1D>> BindingList<Axis3D>
+    for (X, Y, Z)
+{
+}
+
+impl<D, L: BindingList<D>> BindingList<D> for WithModifiers<L> {}
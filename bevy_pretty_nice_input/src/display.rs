@@ -0,0 +1,312 @@
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::binding_parts::{AxisDirection, BindingPart};
+
+/// The kind of device a [`BindingPart`] reads from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Clone, Debug, PartialEq, Hash)]
+pub enum InputDevice {
+    Keyboard,
+    Mouse,
+    Gamepad,
+}
+
+impl BindingPart {
+    pub fn device(&self) -> InputDevice {
+        match self {
+            BindingPart::Key(_) | BindingPart::KeyAxis { .. } => InputDevice::Keyboard,
+            BindingPart::GamepadAxis(_)
+            | BindingPart::GamepadButton(_)
+            | BindingPart::GamepadButtonAxis { .. } => InputDevice::Gamepad,
+            BindingPart::MouseButton(_)
+            | BindingPart::MouseMoveAxis(_)
+            | BindingPart::MouseScroll(_)
+            | BindingPart::MouseScrollAxis(_) => InputDevice::Mouse,
+        }
+    }
+
+    /// A name for showing to players, like `"W"`, `"Left Mouse"` or `"Left Trigger"`.
+    ///
+    /// Unlike [`BindingPart::name`], this doesn't say which kind of binding part it is.
+    /// Axes made of two keys or buttons are shown negative side first, like `"A/D"`.
+    pub fn display_name(&self) -> String {
+        match *self {
+            BindingPart::Key(key) => key_name(key),
+            BindingPart::KeyAxis { pos, neg } => format!("{}/{}", key_name(neg), key_name(pos)),
+            BindingPart::GamepadAxis(axis) => match axis {
+                GamepadAxis::Other(index) => format!("Axis {}", index),
+                axis => split_words(&format!("{:?}", axis)),
+            },
+            BindingPart::GamepadButton(button) => gamepad_button_name(button),
+            BindingPart::GamepadButtonAxis { pos, neg } => {
+                format!("{}/{}", gamepad_button_name(neg), gamepad_button_name(pos))
+            }
+            BindingPart::MouseButton(button) => match button {
+                MouseButton::Left => "Left Mouse".to_string(),
+                MouseButton::Right => "Right Mouse".to_string(),
+                MouseButton::Middle => "Middle Mouse".to_string(),
+                MouseButton::Back => "Mouse Back".to_string(),
+                MouseButton::Forward => "Mouse Forward".to_string(),
+                MouseButton::Other(index) => format!("Mouse {}", index),
+            },
+            BindingPart::MouseMoveAxis(AxisDirection::X) => "Mouse X".to_string(),
+            BindingPart::MouseMoveAxis(AxisDirection::Y) => "Mouse Y".to_string(),
+            BindingPart::MouseScroll(direction) => format!("Scroll {:?}", direction),
+            BindingPart::MouseScrollAxis(AxisDirection::X) => "Horizontal Scroll".to_string(),
+            BindingPart::MouseScrollAxis(AxisDirection::Y) => "Scroll Wheel".to_string(),
+        }
+    }
+}
+
+fn key_name(key: KeyCode) -> String {
+    match key {
+        KeyCode::ArrowUp => "Up".to_string(),
+        KeyCode::ArrowDown => "Down".to_string(),
+        KeyCode::ArrowLeft => "Left".to_string(),
+        KeyCode::ArrowRight => "Right".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        // Punctuation shows the symbol on the key
+        KeyCode::Backquote => "`".to_string(),
+        KeyCode::Backslash | KeyCode::IntlBackslash => "\\".to_string(),
+        KeyCode::BracketLeft => "[".to_string(),
+        KeyCode::BracketRight => "]".to_string(),
+        KeyCode::Comma => ",".to_string(),
+        KeyCode::Equal => "=".to_string(),
+        KeyCode::Minus => "-".to_string(),
+        KeyCode::Period => ".".to_string(),
+        KeyCode::Quote => "'".to_string(),
+        KeyCode::Semicolon => ";".to_string(),
+        KeyCode::Slash => "/".to_string(),
+        KeyCode::IntlYen => "¥".to_string(),
+        KeyCode::NumpadAdd => "Numpad +".to_string(),
+        KeyCode::NumpadSubtract => "Numpad -".to_string(),
+        KeyCode::NumpadMultiply | KeyCode::NumpadStar => "Numpad *".to_string(),
+        KeyCode::NumpadDivide => "Numpad /".to_string(),
+        KeyCode::NumpadDecimal => "Numpad .".to_string(),
+        KeyCode::NumpadComma => "Numpad ,".to_string(),
+        KeyCode::NumpadEqual => "Numpad =".to_string(),
+        KeyCode::NumpadHash => "Numpad #".to_string(),
+        KeyCode::NumpadParenLeft => "Numpad (".to_string(),
+        KeyCode::NumpadParenRight => "Numpad )".to_string(),
+        KeyCode::Unidentified(_) => "Unknown Key".to_string(),
+        key => {
+            let name = format!("{:?}", key);
+            let name = name
+                .strip_prefix("Key")
+                .or_else(|| name.strip_prefix("Digit"))
+                .unwrap_or(&name)
+                .replace("Control", "Ctrl");
+            // ShiftLeft reads better as Left Shift
+            if let Some(name) = name.strip_suffix("Left") {
+                format!("Left {}", split_words(name))
+            } else if let Some(name) = name.strip_suffix("Right") {
+                format!("Right {}", split_words(name))
+            } else {
+                split_words(&name)
+            }
+        }
+    }
+}
+
+fn gamepad_button_name(button: GamepadButton) -> String {
+    match button {
+        GamepadButton::South | GamepadButton::East | GamepadButton::North | GamepadButton::West => {
+            format!("{:?} Button", button)
+        }
+        GamepadButton::LeftTrigger => "Left Bumper".to_string(),
+        GamepadButton::RightTrigger => "Right Bumper".to_string(),
+        GamepadButton::LeftTrigger2 => "Left Trigger".to_string(),
+        GamepadButton::RightTrigger2 => "Right Trigger".to_string(),
+        GamepadButton::LeftThumb => "Left Stick Press".to_string(),
+        GamepadButton::RightThumb => "Right Stick Press".to_string(),
+        GamepadButton::DPadUp => "D-Pad Up".to_string(),
+        GamepadButton::DPadDown => "D-Pad Down".to_string(),
+        GamepadButton::DPadLeft => "D-Pad Left".to_string(),
+        GamepadButton::DPadRight => "D-Pad Right".to_string(),
+        GamepadButton::Other(index) => format!("Button {}", index),
+        button => split_words(&format!("{:?}", button)),
+    }
+}
+
+/// Puts spaces between the words of a `CamelCase` name, and before numbers that follow a word.
+fn split_words(name: &str) -> String {
+    let mut result = String::new();
+    let mut prev: Option<char> = None;
+    for c in name.chars() {
+        if prev.is_some_and(|prev| prev.is_lowercase() && (c.is_uppercase() || c.is_ascii_digit()))
+        {
+            result.push(' ');
+        }
+        result.push(c);
+        prev = Some(c);
+    }
+    result
+}
+
+/// Custom text or glyphs for binding parts, like button icons from a font. Added to [`BindingGlyphs`] per [`InputDevice`].
+pub trait GlyphProvider: Send + Sync + 'static {
+    /// The glyph for the binding part, or `None` to use its [`BindingPart::display_name`].
+    fn glyph(&self, part: &BindingPart) -> Option<String>;
+}
+
+/// Resource with the [`GlyphProvider`] for each [`InputDevice`], used by [`BindingDisplay`].
+///
+/// Devices without a provider use [`BindingPart::display_name`].
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// fn use_playstation_glyphs(mut glyphs: ResMut<BindingGlyphs>) {
+///     glyphs.insert(InputDevice::Gamepad, PlayStationGlyphs);
+/// }
+/// ```
+#[derive(Resource, Default)]
+pub struct BindingGlyphs {
+    providers: HashMap<InputDevice, Box<dyn GlyphProvider>>,
+}
+
+impl BindingGlyphs {
+    /// Sets the provider for a device, replacing any previous one.
+    pub fn insert(&mut self, device: InputDevice, provider: impl GlyphProvider) {
+        self.providers.insert(device, Box::new(provider));
+    }
+
+    /// Goes back to [`BindingPart::display_name`] for a device.
+    pub fn remove(&mut self, device: InputDevice) {
+        self.providers.remove(&device);
+    }
+}
+
+/// Turns bindings into text for showing to players, using the [`BindingGlyphs`].
+///
+/// Implemented for single binding parts and for whole bindings, which show their parts separated by commas,
+/// leaving out repeats like a stick's X and Y axes that share a glyph.
+/// [`InputBindings`](crate::prelude::InputBindings) reads an action's current bindings, so prompts built from them every frame
+/// follow any [`Rebind`](crate::prelude::Rebind).
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn continue_prompt(
+///     player: Single<Entity, With<Player>>,
+///     bindings: InputBindings<Jump>,
+///     glyphs: Res<BindingGlyphs>,
+/// ) -> Result {
+///     if let Some(jump) = bindings.prompt(*player, &[InputDevice::Gamepad], &glyphs)? {
+///         info!("Press {} to continue", jump);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait BindingDisplay {
+    fn display(&self, glyphs: &BindingGlyphs) -> String;
+}
+
+impl BindingDisplay for BindingPart {
+    fn display(&self, glyphs: &BindingGlyphs) -> String {
+        if let Some(glyph) = glyphs
+            .providers
+            .get(&self.device())
+            .and_then(|provider| provider.glyph(self))
+        {
+            return glyph;
+        }
+        match *self {
+            BindingPart::KeyAxis { pos, neg } => format!(
+                "{}/{}",
+                BindingPart::Key(neg).display(glyphs),
+                BindingPart::Key(pos).display(glyphs)
+            ),
+            BindingPart::GamepadButtonAxis { pos, neg } => format!(
+                "{}/{}",
+                BindingPart::GamepadButton(neg).display(glyphs),
+                BindingPart::GamepadButton(pos).display(glyphs)
+            ),
+            part => part.display_name(),
+        }
+    }
+}
+
+impl BindingDisplay for [BindingPart] {
+    fn display(&self, glyphs: &BindingGlyphs) -> String {
+        let mut parts = self
+            .iter()
+            .map(|part| part.display(glyphs))
+            .collect::<Vec<_>>();
+        parts.dedup();
+        parts.join(", ")
+    }
+}
+
+impl BindingDisplay for Vec<BindingPart> {
+    fn display(&self, glyphs: &BindingGlyphs) -> String {
+        self.as_slice().display(glyphs)
+    }
+}
+
+/// [`GlyphProvider`] for gamepads with Xbox button names, like `"Ⓐ"` and `"RT"`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct XboxGlyphs;
+
+impl GlyphProvider for XboxGlyphs {
+    fn glyph(&self, part: &BindingPart) -> Option<String> {
+        let glyph = match part {
+            BindingPart::GamepadButton(button) => match button {
+                GamepadButton::South => "Ⓐ",
+                GamepadButton::East => "Ⓑ",
+                GamepadButton::West => "Ⓧ",
+                GamepadButton::North => "Ⓨ",
+                GamepadButton::LeftTrigger => "LB",
+                GamepadButton::RightTrigger => "RB",
+                GamepadButton::LeftTrigger2 => "LT",
+                GamepadButton::RightTrigger2 => "RT",
+                GamepadButton::LeftThumb => "LS",
+                GamepadButton::RightThumb => "RS",
+                GamepadButton::Select => "View",
+                GamepadButton::Start => "Menu",
+                _ => return None,
+            },
+            BindingPart::GamepadAxis(GamepadAxis::LeftStickX | GamepadAxis::LeftStickY) => "LS",
+            BindingPart::GamepadAxis(GamepadAxis::RightStickX | GamepadAxis::RightStickY) => "RS",
+            _ => return None,
+        };
+        Some(glyph.to_string())
+    }
+}
+
+/// [`GlyphProvider`] for gamepads with PlayStation button names, like `"✕"` and `"R2"`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlayStationGlyphs;
+
+impl GlyphProvider for PlayStationGlyphs {
+    fn glyph(&self, part: &BindingPart) -> Option<String> {
+        let glyph = match part {
+            BindingPart::GamepadButton(button) => match button {
+                GamepadButton::South => "✕",
+                GamepadButton::East => "○",
+                GamepadButton::West => "□",
+                GamepadButton::North => "△",
+                GamepadButton::LeftTrigger => "L1",
+                GamepadButton::RightTrigger => "R1",
+                GamepadButton::LeftTrigger2 => "L2",
+                GamepadButton::RightTrigger2 => "R2",
+                GamepadButton::LeftThumb => "L3",
+                GamepadButton::RightThumb => "R3",
+                GamepadButton::Select => "Create",
+                GamepadButton::Start => "Options",
+                _ => return None,
+            },
+            BindingPart::GamepadAxis(GamepadAxis::LeftStickX | GamepadAxis::LeftStickY) => "L",
+            BindingPart::GamepadAxis(GamepadAxis::RightStickX | GamepadAxis::RightStickY) => "R",
+            _ => return None,
+        };
+        Some(glyph.to_string())
+    }
+}
@@ -9,7 +9,7 @@ use crate::actions::{Action, ActionData};
 pub struct JustPressed<A: Action> {
     #[event_target]
     pub input: Entity,
-    pub data: ActionData,
+    pub data: A::Output,
     pub _marker: PhantomData<A>,
 }
 
@@ -28,7 +28,7 @@ impl<A: Action> Clone for JustPressed<A> {
 pub struct Pressed<A: Action> {
     #[event_target]
     pub input: Entity,
-    pub data: ActionData,
+    pub data: A::Output,
     pub _marker: PhantomData<A>,
 }
 
@@ -64,7 +64,9 @@ impl<A: Action> Clone for JustReleased<A> {
 pub struct Updated<A: Action> {
     #[event_target]
     pub input: Entity,
-    pub data: ActionData,
+    pub data: A::Output,
+    /// The data before it was converted to [`Action::Output`].
+    pub raw: ActionData,
     pub _marker: PhantomData<A>,
 }
 
@@ -73,6 +75,28 @@ impl<A: Action> Clone for Updated<A> {
         Self {
             input: self.input,
             data: self.data,
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Event fired while a [`Hold`](crate::prelude::Hold) condition is held, with how far along it is in \[0,1\].
+///
+/// Fired with `0.0` when the hold is let go. Other [`StatefulCondition`](crate::prelude::StatefulCondition)s can fire it too with their [`progress`](crate::prelude::StatefulCondition::progress).
+#[derive(EntityEvent, Debug, Reflect)]
+pub struct HoldProgress<A: Action> {
+    #[event_target]
+    pub input: Entity,
+    pub progress: f32,
+    pub _marker: PhantomData<A>,
+}
+
+impl<A: Action> Clone for HoldProgress<A> {
+    fn clone(&self) -> Self {
+        Self {
+            input: self.input,
+            progress: self.progress,
             _marker: PhantomData,
         }
     }
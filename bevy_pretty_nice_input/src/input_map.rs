@@ -0,0 +1,394 @@
+//! Input maps, which describe actions' bindings and conditions in asset files instead of [`input!`](crate::prelude::input).
+//!
+//! Input map files end in `.input.ron` and are read through reflection, so they look like the Rust types below.
+//!
+//! ```ron
+//! (
+//!     actions: {
+//!         "Jump": (
+//!             bindings: [
+//!                 (parts: [Key(Space)]),
+//!                 (parts: [GamepadButton(South)]),
+//!             ],
+//!             conditions: [ButtonPress(0.5), Cooldown(0.5)],
+//!         ),
+//!         "Walk": (
+//!             bindings: [
+//!                 (parts: [KeyAxis(pos: KeyD, neg: KeyA), KeyAxis(pos: KeyW, neg: KeyS)]),
+//!                 (
+//!                     parts: [GamepadAxis(LeftStickX), GamepadAxis(LeftStickY)],
+//!                     modifiers: [RadialDeadzone(inner: 0.15, outer: 1.0)],
+//!                 ),
+//!             ],
+//!             aggregation: SumClamped,
+//!         ),
+//!     },
+//! )
+//! ```
+//!
+//! Add [`InputMapActions`] to an input system entity to build its actions from an input map.
+//! With Bevy's `file_watcher` feature, changing the file rebuilds them while the game is running.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::ecs::lifecycle::HookContext;
+use bevy::ecs::relationship::RelatedSpawnerCommands;
+use bevy::ecs::world::DeferredWorld;
+use bevy::platform::collections::{HashMap, HashSet};
+use bevy::prelude::*;
+use bevy::reflect::TypeRegistryArc;
+use bevy::reflect::serde::TypedReflectDeserializer;
+use serde::de::DeserializeSeed;
+
+use crate::actions::{Action, ActionData, ActionOf, Actions, PrevAction2Data, PrevActionData};
+use crate::binding_parts::{BindingPart, BindingPartData, BindingPartOf};
+use crate::bindings::{BindingAggregation, BindingData, BindingOf};
+use crate::bundles::{add_systems_once_to_world, observe};
+use crate::conditions::{
+    ButtonPress, ButtonRelease, Condition, ConditionOf, Cooldown, Hold, InputBuffer, MultiTap,
+    Repeat, ResetBuffer, Tap, Toggle,
+};
+use crate::modifiers::{Modifier, Modifiers};
+use crate::{BindingPartSystems, PrettyNiceInputSystems};
+
+/// Asset describing the bindings and conditions of any number of actions, keyed by the actions' short type names.
+#[derive(Asset, Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct InputMap {
+    pub actions: HashMap<String, ActionMap>,
+}
+
+impl InputMap {
+    /// The entry for the [`Action`], found by its [`ShortName`].
+    pub fn get<A: Action>(&self) -> Option<&ActionMap> {
+        self.actions.get(&ShortName::of::<A>().to_string())
+    }
+}
+
+/// Everything [`input!`](crate::prelude::input) would be given for a single action.
+#[derive(Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct ActionMap {
+    pub bindings: Vec<BindingMap>,
+    #[reflect(default)]
+    pub conditions: Vec<ConditionMap>,
+    #[reflect(default)]
+    pub aggregation: BindingAggregation,
+}
+
+/// A single binding, with one part per axis like the tuples in [`binding2d`](crate::binding2d).
+#[derive(Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct BindingMap {
+    pub parts: Vec<BindingPart>,
+    #[reflect(default)]
+    pub modifiers: Vec<Modifier>,
+}
+
+/// The [`Condition`]s that can be described by data alone.
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub enum ConditionMap {
+    /// [`ButtonPress`] with this threshold.
+    ButtonPress(f32),
+    /// [`ButtonRelease`] with this threshold.
+    ButtonRelease(f32),
+    /// [`Cooldown`] with this duration.
+    Cooldown(f32),
+    /// [`Hold`] with this duration.
+    Hold(f32),
+    /// [`Tap`] with this max duration.
+    Tap(f32),
+    MultiTap {
+        count: u32,
+        max_gap: f32,
+    },
+    Repeat {
+        initial_delay: f32,
+        interval: f32,
+    },
+    /// [`InputBuffer`] with this duration.
+    InputBuffer(f32),
+    /// [`Toggle`] with this threshold.
+    Toggle(f32),
+    ResetBuffer,
+}
+
+impl ActionMap {
+    /// Replaces the entity's `Actions<A>` with ones built from this map, the same as [`input!`](crate::prelude::input) would build them.
+    ///
+    /// Unlike [`input!`](crate::prelude::input), this doesn't observe re-enabling input, which [`InputMapActions`] handles.
+    pub fn apply<A: Action>(&self, entity: &mut EntityCommands) -> Result {
+        let dimension = self
+            .bindings
+            .first()
+            .map(|binding| binding.parts.len())
+            .ok_or_else(|| BevyError::from(format!("{} has no bindings", ShortName::of::<A>())))?;
+        if let Some(binding) = self
+            .bindings
+            .iter()
+            .find(|binding| binding.parts.len() != dimension)
+        {
+            return Err(BevyError::from(format!(
+                "{} has bindings with {} and {} parts",
+                ShortName::of::<A>(),
+                dimension,
+                binding.parts.len()
+            )));
+        }
+        if let Some(expected) = A::Output::DIMENSION
+            && expected != dimension
+        {
+            return Err(BevyError::from(format!(
+                "{} outputs {} and needs bindings with {} parts, but has bindings with {} parts",
+                ShortName::of::<A>(),
+                ShortName::of::<A::Output>(),
+                expected,
+                dimension
+            )));
+        }
+        let zero = match dimension {
+            1 => ActionData::Axis1D(Default::default()),
+            2 => ActionData::Axis2D(Default::default()),
+            3 => ActionData::Axis3D(Default::default()),
+            _ => {
+                return Err(BevyError::from(format!(
+                    "Binding has invalid number of parts: {}",
+                    dimension
+                )));
+            }
+        };
+
+        let name = ShortName::of::<A>();
+        let map = self.clone();
+        entity
+            .despawn_related::<Actions<A>>()
+            .with_related_entities::<ActionOf<A>>(|actions| {
+                actions
+                    .spawn((
+                        Name::new(format!("{} Action", name)),
+                        PrevActionData(zero),
+                        PrevAction2Data::default(),
+                        map.aggregation,
+                        observe(crate::derive::action::<A>),
+                        observe(crate::derive::action_2::<A>),
+                        observe(crate::derive::action_2_invalidate::<A>),
+                    ))
+                    .with_related_entities::<BindingOf>(|bindings| {
+                        for binding in map.bindings {
+                            bindings
+                                .spawn((
+                                    Name::new(format!("{} Binding", name)),
+                                    BindingData::new(zero),
+                                    Modifiers(binding.modifiers),
+                                    observe(crate::derive::binding),
+                                ))
+                                .with_related_entities::<BindingPartOf>(|parts| {
+                                    for part in binding.parts {
+                                        parts.spawn(BindingPartData::default()).queue(
+                                            move |mut entity: EntityWorldMut| {
+                                                part.insert_into(&mut entity)
+                                            },
+                                        );
+                                    }
+                                });
+                        }
+                    })
+                    .with_related_entities::<ConditionOf>(|conditions| {
+                        spawn_condition::<A, _>(conditions, A::EnableFilter::default());
+                        for condition in map.conditions {
+                            match condition {
+                                ConditionMap::ButtonPress(threshold) => {
+                                    spawn_condition::<A, _>(conditions, ButtonPress::new(threshold))
+                                }
+                                ConditionMap::ButtonRelease(threshold) => spawn_condition::<A, _>(
+                                    conditions,
+                                    ButtonRelease::new(threshold),
+                                ),
+                                ConditionMap::Cooldown(duration) => {
+                                    spawn_condition::<A, _>(conditions, Cooldown::new(duration))
+                                }
+                                ConditionMap::Hold(duration) => {
+                                    spawn_condition::<A, _>(conditions, Hold::new(duration))
+                                }
+                                ConditionMap::Tap(max_duration) => {
+                                    spawn_condition::<A, _>(conditions, Tap::new(max_duration))
+                                }
+                                ConditionMap::MultiTap { count, max_gap } => {
+                                    spawn_condition::<A, _>(
+                                        conditions,
+                                        MultiTap::new(count, max_gap),
+                                    )
+                                }
+                                ConditionMap::Repeat {
+                                    initial_delay,
+                                    interval,
+                                } => spawn_condition::<A, _>(
+                                    conditions,
+                                    Repeat::new(initial_delay, interval),
+                                ),
+                                ConditionMap::InputBuffer(duration) => {
+                                    spawn_condition::<A, _>(conditions, InputBuffer::new(duration))
+                                }
+                                ConditionMap::Toggle(threshold) => spawn_condition::<A, _>(
+                                    conditions,
+                                    Toggle::default().with_threshold(threshold),
+                                ),
+                                ConditionMap::ResetBuffer => {
+                                    spawn_condition::<A, _>(conditions, ResetBuffer)
+                                }
+                            }
+                        }
+                    });
+            });
+        Ok(())
+    }
+}
+
+fn spawn_condition<A: Action, C: Condition + Component>(
+    conditions: &mut RelatedSpawnerCommands<ConditionOf>,
+    condition: C,
+) {
+    conditions.spawn((
+        Name::new(format!("{} Condition", ShortName::of::<A>())),
+        (
+            condition.bundle::<A>(),
+            condition,
+            observe(crate::derive::invalidate_pass),
+        ),
+    ));
+}
+
+/// Component for input system entities that builds their `Actions<A>` from an [`InputMap`] once it loads,
+/// and rebuilds them whenever it changes.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # use bevy_pretty_nice_input::input_map::InputMapActions;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// #[derive(Action)]
+/// struct Walk;
+///
+/// fn spawn_player(asset_server: Res<AssetServer>, mut commands: Commands) {
+///     let input_map = asset_server.load("controls.input.ron");
+///     commands.spawn((
+///         InputMapActions::<Jump>::new(input_map.clone()),
+///         InputMapActions::<Walk>::new(input_map),
+///     ));
+/// }
+/// ```
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+#[component(on_add)]
+pub struct InputMapActions<A: Action> {
+    pub input_map: Handle<InputMap>,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Action> InputMapActions<A> {
+    pub fn new(input_map: Handle<InputMap>) -> Self {
+        Self {
+            input_map,
+            _marker: PhantomData,
+        }
+    }
+
+    fn on_add(mut world: DeferredWorld, context: HookContext) {
+        world
+            .commands()
+            .entity(context.entity)
+            .insert(observe(crate::derive::action_enable::<A>));
+        world.commands().queue(|world: &mut World| {
+            add_systems_once_to_world(world, TypeId::of::<InputMapActions<A>>(), PreUpdate, || {
+                apply_input_maps::<A>
+                    .in_set(PrettyNiceInputSystems)
+                    .before(BindingPartSystems)
+                    .into_configs()
+            });
+        });
+    }
+}
+
+fn apply_input_maps<A: Action>(
+    mut asset_events: MessageReader<AssetEvent<InputMap>>,
+    input_maps: Res<Assets<InputMap>>,
+    inputs: Query<(Entity, Ref<InputMapActions<A>>)>,
+    mut commands: Commands,
+) {
+    let changed = asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect::<HashSet<_>>();
+
+    for (entity, input_map_actions) in inputs.iter() {
+        let id = input_map_actions.input_map.id();
+        if !input_map_actions.is_changed() && !changed.contains(&id) {
+            continue;
+        }
+        // Not loaded yet, so it'll be applied when it is
+        let Some(input_map) = input_maps.get(id) else {
+            continue;
+        };
+        // Skip just this entity, so one bad map doesn't keep the others from being applied
+        let Some(action_map) = input_map.get::<A>() else {
+            warn!(
+                "Input map for {} has no {} action",
+                entity,
+                ShortName::of::<A>()
+            );
+            continue;
+        };
+        if let Err(error) = action_map.apply::<A>(&mut commands.entity(entity)) {
+            warn!("Couldn't apply input map to {}: {}", entity, error);
+        }
+    }
+}
+
+/// [`AssetLoader`] for `.input.ron` files, added by [`PrettyNiceInputPlugin`](crate::PrettyNiceInputPlugin).
+#[derive(TypePath)]
+pub struct InputMapLoader {
+    registry: TypeRegistryArc,
+}
+
+impl FromWorld for InputMapLoader {
+    fn from_world(world: &mut World) -> Self {
+        Self {
+            registry: world.resource::<AppTypeRegistry>().0.clone(),
+        }
+    }
+}
+
+impl AssetLoader for InputMapLoader {
+    type Asset = InputMap;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<InputMap> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let registry = self.registry.read();
+        let mut deserializer = ron::Deserializer::from_bytes(&bytes)?;
+        let input_map =
+            TypedReflectDeserializer::of::<InputMap>(&registry).deserialize(&mut deserializer)?;
+        InputMap::from_reflect(&*input_map)
+            .ok_or_else(|| BevyError::from("Input map doesn't match the InputMap type"))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["input.ron"]
+    }
+}
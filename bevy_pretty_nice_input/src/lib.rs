@@ -9,18 +9,30 @@
 
 use bevy::prelude::*;
 
+mod action_state;
 mod actions;
 pub mod binding1d;
 pub mod binding2d;
+pub mod binding3d;
 mod binding_parts;
 mod bindings;
 pub mod bundles;
 mod conditions;
+mod conflicts;
+mod contexts;
 #[cfg(feature = "debug_graph")]
 pub mod debug_graph;
 #[doc(hidden)]
 pub mod derive;
+pub mod dimension;
+mod display;
 mod events;
+#[cfg(feature = "asset")]
+pub mod input_map;
+mod modifiers;
+pub mod predicates;
+mod profile;
+mod rebind;
 
 /// Re-exports from [`bevy_event_chain`].
 pub mod bevy_event_chain {
@@ -34,14 +46,35 @@ pub mod bevy_event_chain {
 /// Note that every other type used in the crate that has to be public because they're used in macros is in the hidden `derive` mod.
 pub mod prelude {
     pub use crate::PrettyNiceInputPlugin;
-    pub use crate::actions::{Action, ActionData};
+    pub use crate::action_state::ActionState;
+    pub use crate::actions::{Action, ActionData, ActionOutput};
+    pub use crate::binding_parts::{
+        AxisDirection, BindingPart, MouseScrollDirection, OwnedGamepad,
+    };
+    pub use crate::bindings::BindingAggregation;
     pub use crate::conditions::{
-        ButtonPress, ButtonRelease, ComponentBuffer, Condition, ConditionedBindingUpdate, Cooldown,
-        Filter, FilterBuffered, InputBuffer, InputDisabled, InvalidateData, InvalidatingFilter,
-        Invert, IsInputEnabled, IsInputEnabledInvalidate, ResetBuffer,
+        ButtonPress, ButtonRelease, Chord, ComponentBuffer, Condition, ConditionContext,
+        ConditionedBindingUpdate, Cooldown, Filter, FilterBuffered, Hold, InputBuffer,
+        InputDisabled, InvalidateData, InvalidatingFilter, Invert, IsInputEnabled,
+        IsInputEnabledInvalidate, Map, MapContext, MapWith, MultiTap, Outcome, Repeat, ResetBuffer,
+        Sequence, StatefulCondition, Tap, Toggle, ToggleSetting, ToggleWhen, When,
+    };
+    pub use crate::conflicts::{
+        BindingConflict, BindingConflicts, BoundPart, warn_binding_conflicts,
+    };
+    pub use crate::contexts::{InContext, InputContext, InputContexts};
+    pub use crate::dimension::BindingList;
+    pub use crate::display::{
+        BindingDisplay, BindingGlyphs, GlyphProvider, InputDevice, PlayStationGlyphs, XboxGlyphs,
+    };
+    pub use crate::events::{HoldProgress, JustPressed, JustReleased, Pressed, Updated};
+    pub use crate::modifiers::{Modifier, Modifiers, with_modifiers};
+    pub use crate::predicates::InputPredicate;
+    pub use crate::profile::{ActionProfile, ActionSettings, ControlProfile};
+    pub use crate::rebind::{
+        CaptureInput, InputBindings, InputCaptured, Rebind, RebindConflicts, ResetBindings,
     };
-    pub use crate::events::{JustPressed, JustReleased, Pressed, Updated};
-    pub use crate::{binding1d, binding2d};
+    pub use crate::{binding1d, binding2d, binding3d, predicates};
 
     /// Derive for [`TryFrom<ActionData, Error = BevyError>`].
     ///
@@ -58,6 +91,33 @@ pub mod prelude {
     ///
     /// [`IsInputEnabledInvalidate`] works best for state-agnostic inputs, like opening/closing menus, where keeping the previous input would be harmful.
     /// [`IsInputEnabled`] works best for state machines, when controls can change while the input is disabled.
+    ///
+    /// It also accepts `output = Type` to set what the action's events carry their data as, defaulting to [`ActionData`].
+    /// Any [`ActionOutput`] works, which includes `bool`, `f32`, [`Vec2`] and [`Vec3`].
+    /// [`input!`] fails to compile if the bindings' dimension doesn't match the output.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// #[derive(Action)]
+    /// #[action(output = Vec2)]
+    /// struct Look;
+    ///
+    /// fn look(look: On<Pressed<Look>>) {
+    ///     let delta: Vec2 = look.data;
+    /// }
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// #[derive(Action)]
+    /// #[action(output = Vec2)]
+    /// struct Look;
+    ///
+    /// input!(Look, Axis1D[binding1d::space()])
+    /// # ;
+    /// ```
     pub use bevy_pretty_nice_input_derive::Action;
 
     /// Takes an [`Action`] type, some bindings, and optionally some conditions, and returns a component bundle for the input system entity.
@@ -71,6 +131,50 @@ pub mod prelude {
     /// input!(Walk, Axis2D[binding2d::wasd()], [Cooldown::new(0.5)])
     /// # ;
     /// ```
+    ///
+    /// Each binding must be a [`BindingList`] of the given dimension, so mismatched bindings fail to compile.
+    ///
+    /// ```rust,compile_fail
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// # #[derive(Action)]
+    /// # struct Walk;
+    /// input!(Walk, Axis1D[binding2d::wasd()])
+    /// # ;
+    /// ```
+    ///
+    /// Options may be given after the bindings and conditions as `name = value`.
+    /// `aggregation` takes a [`BindingAggregation`] that decides how multiple bindings are combined.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// # #[derive(Action)]
+    /// # struct Walk;
+    /// input!(
+    ///     Walk,
+    ///     Axis2D[binding2d::wasd(), binding2d::arrow_keys()],
+    ///     aggregation = BindingAggregation::SumClamped,
+    /// )
+    /// # ;
+    /// ```
+    ///
+    /// A binding may be followed by `=> [...]` to give it [`Modifier`]s, which change its value before it reaches the conditions.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// # #[derive(Action)]
+    /// # struct Walk;
+    /// input!(
+    ///     Walk,
+    ///     Axis2D[
+    ///         binding2d::wasd(),
+    ///         binding2d::left_stick() => [Modifier::radial_deadzone(0.15), Modifier::Power(2.0)],
+    ///     ]
+    /// )
+    /// # ;
+    /// ```
     pub use bevy_pretty_nice_input_derive::input;
 
     /// Uses [`input!`] and bundles of components to act as a state machine. Returns a component bundle.
@@ -174,7 +278,7 @@ pub mod prelude {
     /// # ;
     /// ```
     ///
-    /// Conditions may be used in the transition, same as in [`input!`], but only for unidirectional transitions.
+    /// Conditions may be used in the transition, same as in [`input!`].
     ///
     /// ```rust
     /// # use bevy::prelude::*;
@@ -188,6 +292,18 @@ pub mod prelude {
     /// input_transition!((Standing) => (Walking), Axis2D[binding2d::wasd()], [Filter::<With<Grounded>>::default()])
     /// # ;
     /// ```
+    ///
+    /// In bidirectional transitions, conditions see every update before the *from* side is checked,
+    /// so both directions share the same view of the input. This is what lets [`Toggle`] latch a state on and off:
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// # #[derive(Component, Default)]
+    /// # struct Crouching;
+    /// input_transition!(() <=> (Crouching), Axis1D[binding1d::left_ctrl()], [Toggle::default()])
+    /// # ;
+    /// ```
     pub use bevy_pretty_nice_input_derive::input_transition;
 }
 
@@ -199,30 +315,52 @@ pub struct PrettyNiceInputPlugin;
 #[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
 pub struct PrettyNiceInputSystems;
 
+/// [`SystemSet`] for systems that read input devices into binding parts.
+#[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct BindingPartSystems;
+
+/// [`SystemSet`] for systems that tick conditions, which run after [`BindingPartSystems`].
+#[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
+pub(crate) struct ConditionTickSystems;
+
 impl Plugin for PrettyNiceInputPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(
+        app.configure_sets(
+            PreUpdate,
+            (BindingPartSystems, ConditionTickSystems)
+                .chain()
+                .in_set(PrettyNiceInputSystems),
+        )
+        .add_systems(
             PreUpdate,
             (
                 (
                     binding_parts::binding_part_key,
                     binding_parts::binding_part_key_axis,
                     binding_parts::binding_part_gamepad_axis,
+                    binding_parts::binding_part_gamepad_button,
+                    binding_parts::binding_part_gamepad_button_axis,
                     binding_parts::binding_part_mouse_button,
                     binding_parts::binding_part_mouse_move,
                     binding_parts::binding_part_mouse_scroll,
                     binding_parts::binding_part_mouse_scroll_axis,
-                ),
-                (
-                    conditions::tick_cooldown,
-                    conditions::tick_input_buffer,
-                    derive::action_initialize,
-                ),
-            )
-                .chain()
-                .in_set(PrettyNiceInputSystems),
+                )
+                    .in_set(BindingPartSystems),
+                (rebind::capture_input, contexts::refresh_input_contexts)
+                    .in_set(PrettyNiceInputSystems)
+                    .before(BindingPartSystems),
+                derive::action_initialize.in_set(ConditionTickSystems),
+            ),
         )
-        .add_observer(conditions::pass_reset_buffer);
+        .add_systems(Last, bundles::add_pending_systems)
+        .add_observer(conditions::pass_reset_buffer)
+        .init_resource::<display::BindingGlyphs>()
+        .register_type::<profile::ControlProfile>();
+
+        #[cfg(feature = "asset")]
+        app.init_asset::<input_map::InputMap>()
+            .init_asset_loader::<input_map::InputMapLoader>()
+            .register_type::<input_map::InputMap>();
 
         #[cfg(feature = "debug_graph")]
         app.init_resource::<debug_graph::DebugGraph>();
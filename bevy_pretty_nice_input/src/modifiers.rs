@@ -0,0 +1,180 @@
+use bevy::ecs::spawn::SpawnableList;
+use bevy::prelude::*;
+use bevy::ptr::{MovingPtr, move_as_ptr};
+
+use crate::actions::ActionData;
+use crate::binding_parts::{BindingPartOf, BindingParts};
+
+/// Processing step applied to a binding's value before it's passed to the action's [conditions](crate::prelude::Condition).
+///
+/// Add these to a whole binding in [`input!`](crate::prelude::input) with `binding => [modifiers]`,
+/// or to each part of a binding with [`with_modifiers`].
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Debug, PartialEq)]
+pub enum Modifier {
+    /// Zeroes each axis below `inner`, saturates each axis above `outer`, and rescales each axis in between to \[0,1\].
+    AxialDeadzone { inner: f32, outer: f32 },
+    /// Same as [`Modifier::AxialDeadzone`], but using the length of the whole value and keeping its direction.
+    RadialDeadzone { inner: f32, outer: f32 },
+    /// Raises the length of the value to this power, keeping its direction.
+    Power(f32),
+    /// Maps the length of the value through `(e^(k*x) - 1) / (e^k - 1)`, keeping its direction. Meant for lengths in \[0,1\].
+    Exponential(f32),
+    /// Multiplies each axis.
+    Scale(Vec3),
+    /// Negates the chosen axes.
+    Invert(BVec3),
+    /// Clamps each axis.
+    Clamp { min: f32, max: f32 },
+}
+
+impl Modifier {
+    pub fn deadzone(inner: f32) -> Self {
+        Modifier::AxialDeadzone { inner, outer: 1.0 }
+    }
+
+    pub fn radial_deadzone(inner: f32) -> Self {
+        Modifier::RadialDeadzone { inner, outer: 1.0 }
+    }
+
+    pub fn scale(scale: f32) -> Self {
+        Modifier::Scale(Vec3::splat(scale))
+    }
+
+    pub fn invert() -> Self {
+        Modifier::Invert(BVec3::TRUE)
+    }
+
+    pub fn invert_x() -> Self {
+        Modifier::Invert(BVec3::new(true, false, false))
+    }
+
+    pub fn invert_y() -> Self {
+        Modifier::Invert(BVec3::new(false, true, false))
+    }
+
+    pub fn invert_z() -> Self {
+        Modifier::Invert(BVec3::new(false, false, true))
+    }
+
+    pub fn clamp(min: f32, max: f32) -> Self {
+        Modifier::Clamp { min, max }
+    }
+
+    pub fn apply(&self, data: ActionData) -> ActionData {
+        let axes = data.axes();
+        let axes = match *self {
+            Modifier::AxialDeadzone { inner, outer } => {
+                axes.map(|axis| axis.signum() * deadzone(axis.abs(), inner, outer))
+            }
+            Modifier::RadialDeadzone { inner, outer } => {
+                with_length(axes, |length| deadzone(length, inner, outer))
+            }
+            Modifier::Power(power) => with_length(axes, |length| length.powf(power)),
+            Modifier::Exponential(k) => with_length(axes, |length| {
+                if k == 0.0 {
+                    length
+                } else {
+                    ((k * length).exp() - 1.0) / (k.exp() - 1.0)
+                }
+            }),
+            Modifier::Scale(scale) => axes * scale,
+            Modifier::Invert(invert) => Vec3::select(invert, -axes, axes),
+            Modifier::Clamp { min, max } => axes.clamp(Vec3::splat(min), Vec3::splat(max)),
+        };
+        data.with_axes(axes)
+    }
+}
+
+fn deadzone(value: f32, inner: f32, outer: f32) -> f32 {
+    if value <= inner {
+        0.0
+    } else if value >= outer {
+        1.0
+    } else {
+        (value - inner) / (outer - inner)
+    }
+}
+
+fn with_length(axes: Vec3, f: impl Fn(f32) -> f32) -> Vec3 {
+    let length = axes.length();
+    if length == 0.0 {
+        axes
+    } else {
+        axes / length * f(length)
+    }
+}
+
+/// [`Modifier`]s for a binding or binding part, applied in order.
+#[derive(Component, Clone, Default, Debug, Reflect)]
+#[reflect(Component, Clone, Default, Debug)]
+pub struct Modifiers(pub Vec<Modifier>);
+
+impl Modifiers {
+    pub fn apply(&self, data: ActionData) -> ActionData {
+        self.0
+            .iter()
+            .fold(data, |data, modifier| modifier.apply(data))
+    }
+}
+
+/// Adds [`Modifier`]s to every binding part in a binding.
+///
+/// Each part's value is 1D, so radial modifiers like [`Modifier::RadialDeadzone`] act the same as their axial versions here.
+/// To process a stick as a whole, add them to the binding with `binding => [modifiers]` instead.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Walk;
+///
+/// input!(
+///     Walk,
+///     Axis2D[(
+///         with_modifiers(binding1d::gamepad_axis(GamepadAxis::LeftStickX), [Modifier::deadzone(0.1)]),
+///         binding1d::gamepad_axis(GamepadAxis::LeftStickY),
+///     )]
+/// )
+/// # ;
+/// ```
+pub fn with_modifiers<L: SpawnableList<BindingPartOf>>(
+    parts: L,
+    modifiers: impl IntoIterator<Item = Modifier>,
+) -> WithModifiers<L> {
+    WithModifiers {
+        parts,
+        modifiers: Modifiers(modifiers.into_iter().collect()),
+    }
+}
+
+/// [`SpawnableList`] returned by [`with_modifiers`].
+pub struct WithModifiers<L> {
+    parts: L,
+    modifiers: Modifiers,
+}
+
+impl<L: SpawnableList<BindingPartOf>> SpawnableList<BindingPartOf> for WithModifiers<L> {
+    fn spawn(this: MovingPtr<'_, Self>, world: &mut World, entity: Entity) {
+        let WithModifiers { parts, modifiers } = this.read();
+        let spawned_before = world
+            .get::<BindingParts>(entity)
+            .map(|binding_parts| binding_parts.len())
+            .unwrap_or_default();
+
+        move_as_ptr!(parts);
+        L::spawn(parts, world, entity);
+
+        let spawned = world
+            .get::<BindingParts>(entity)
+            .map(|binding_parts| binding_parts.collection()[spawned_before..].to_vec())
+            .unwrap_or_default();
+        for part in spawned {
+            world.entity_mut(part).insert(modifiers.clone());
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        self.parts.size_hint()
+    }
+}
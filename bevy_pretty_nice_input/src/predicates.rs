@@ -0,0 +1,199 @@
+//! [`InputPredicate`]s for the [`When`](crate::prelude::When) condition.
+
+use std::marker::PhantomData;
+
+use bevy::ecs::query::QueryFilter;
+use bevy::ecs::system::BoxedSystem;
+use bevy::prelude::*;
+
+use crate::conditions::ConditionedBindingUpdate;
+
+/// Check against the world that decides whether a [`When`](crate::prelude::When) condition lets the input pass.
+pub trait InputPredicate: Send + Sync + 'static {
+    fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool;
+}
+
+/// Passes while the [`State<S>`] is the given value.
+#[derive(Debug)]
+pub struct InState<S: States>(pub S);
+
+impl<S: States> InputPredicate for InState<S> {
+    fn test(&mut self, _update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        world
+            .get_resource::<State<S>>()
+            .is_some_and(|state| *state.get() == self.0)
+    }
+}
+
+/// Passes while the resource `R` exists.
+#[derive(Debug)]
+pub struct ResourceExists<R: Resource>(PhantomData<R>);
+
+impl<R: Resource> Default for ResourceExists<R> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<R: Resource> InputPredicate for ResourceExists<R> {
+    fn test(&mut self, _update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        world.contains_resource::<R>()
+    }
+}
+
+/// Passes while the resource `R` exists and the function returns `true` for it.
+#[derive(Debug)]
+pub struct ResourceMatches<R: Resource>(pub fn(&R) -> bool);
+
+impl<R: Resource> InputPredicate for ResourceMatches<R> {
+    fn test(&mut self, _update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        world.get_resource::<R>().is_some_and(self.0)
+    }
+}
+
+/// Passes while the query filter matches the input system entity, like [`Filter`](crate::prelude::Filter) but combinable.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// use bevy_pretty_nice_input::predicates::{AnyOf, InState, Matches};
+///
+/// # #[derive(Action)]
+/// # struct Jump;
+/// #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+/// enum GameState {
+///     #[default]
+///     Menu,
+///     Playing,
+/// }
+///
+/// #[derive(Component)]
+/// struct Ghost;
+///
+/// input!(
+///     Jump,
+///     Axis1D[binding1d::space()],
+///     [When::new(AnyOf((
+///         Matches::<With<Ghost>>::default(),
+///         InState(GameState::Playing),
+///     )))]
+/// )
+/// # ;
+/// ```
+pub struct Matches<F: QueryFilter> {
+    state: Option<QueryState<(), F>>,
+}
+
+impl<F: QueryFilter> Default for Matches<F> {
+    fn default() -> Self {
+        Self { state: None }
+    }
+}
+
+impl<F: QueryFilter + 'static> InputPredicate for Matches<F> {
+    fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        self.state
+            .get_or_insert_with(|| QueryState::new(world))
+            .get(world, update.input)
+            .is_ok()
+    }
+}
+
+/// Passes when the system returns `true`. Made with [`system`].
+pub struct SystemPredicate {
+    system: BoxedSystem<In<ConditionedBindingUpdate>, bool>,
+    initialized: bool,
+}
+
+/// Runs a system that takes the update being checked, like a run condition.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// # #[derive(Action)]
+/// # struct Jump;
+/// #[derive(Component)]
+/// struct Stunned;
+///
+/// fn not_stunned(update: In<ConditionedBindingUpdate>, stunned: Query<(), With<Stunned>>) -> bool {
+///     !stunned.contains(update.input)
+/// }
+///
+/// input!(Jump, Axis1D[binding1d::space()], [When::new(predicates::system(not_stunned))])
+/// # ;
+/// ```
+pub fn system<M>(
+    system: impl IntoSystem<In<ConditionedBindingUpdate>, bool, M>,
+) -> SystemPredicate {
+    SystemPredicate {
+        system: Box::new(IntoSystem::into_system(system)),
+        initialized: false,
+    }
+}
+
+impl InputPredicate for SystemPredicate {
+    fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        if !self.initialized {
+            self.system.initialize(world);
+            self.initialized = true;
+        }
+        match self.system.run(update.clone(), world) {
+            Ok(passed) => passed,
+            Err(error) => {
+                warn!(
+                    "Predicate system {} failed, so it doesn't pass: {}",
+                    self.system.name(),
+                    error
+                );
+                false
+            }
+        }
+    }
+}
+
+/// Passes when the inner predicate doesn't.
+#[derive(Debug)]
+pub struct Not<P: InputPredicate>(pub P);
+
+impl<P: InputPredicate> InputPredicate for Not<P> {
+    fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+        !self.0.test(update, world)
+    }
+}
+
+/// Passes when every predicate in the tuple passes.
+#[derive(Debug)]
+pub struct AllOf<T>(pub T);
+
+/// Passes when any predicate in the tuple passes.
+#[derive(Debug)]
+pub struct AnyOf<T>(pub T);
+
+macro_rules! impl_tuple_predicates {
+    ($($p:ident),*) => {
+        impl<$($p: InputPredicate),*> InputPredicate for AllOf<($($p,)*)> {
+            #[allow(non_snake_case)]
+            fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+                let ($($p,)*) = &mut self.0;
+                true $(&& $p.test(update, world))*
+            }
+        }
+
+        impl<$($p: InputPredicate),*> InputPredicate for AnyOf<($($p,)*)> {
+            #[allow(non_snake_case)]
+            fn test(&mut self, update: &ConditionedBindingUpdate, world: &mut World) -> bool {
+                let ($($p,)*) = &mut self.0;
+                false $(|| $p.test(update, world))*
+            }
+        }
+    };
+}
+
+impl_tuple_predicates!(P0);
+impl_tuple_predicates!(P0, P1);
+impl_tuple_predicates!(P0, P1, P2);
+impl_tuple_predicates!(P0, P1, P2, P3);
+impl_tuple_predicates!(P0, P1, P2, P3, P4);
+impl_tuple_predicates!(P0, P1, P2, P3, P4, P5);
+impl_tuple_predicates!(P0, P1, P2, P3, P4, P5, P6);
+impl_tuple_predicates!(P0, P1, P2, P3, P4, P5, P6, P7);
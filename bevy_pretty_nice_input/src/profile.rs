@@ -0,0 +1,282 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::system::SystemState;
+use bevy::platform::collections::HashMap;
+use bevy::prelude::*;
+
+use crate::actions::{Action, ActionData};
+use crate::binding_parts::BindingPart;
+use crate::rebind::{InputBindings, Rebind};
+
+/// Per-player settings for an [`Action`], on the input system entity.
+///
+/// Sensitivity and inverting Y change the value that reaches the action's events and [`ActionState`](crate::prelude::ActionState),
+/// after its [`Condition`](crate::prelude::Condition)s, so thresholds aren't affected.
+/// Toggling only does anything for actions with a [`ToggleSetting`](crate::prelude::ToggleSetting) condition.
+#[derive(Component, Debug, Reflect)]
+#[reflect(Component)]
+pub struct ActionSettings<A: Action> {
+    /// Multiplies every axis of the value.
+    pub sensitivity: f32,
+    /// Negates the Y axis, or the only axis of a 1-dimensional action.
+    pub invert_y: bool,
+    /// Whether presses toggle the action instead of holding it.
+    pub toggle: bool,
+    _marker: PhantomData<A>,
+}
+
+impl<A: Action> Default for ActionSettings<A> {
+    fn default() -> Self {
+        Self {
+            sensitivity: 1.0,
+            invert_y: false,
+            toggle: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<A: Action> ActionSettings<A> {
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    pub fn with_invert_y(mut self, invert_y: bool) -> Self {
+        self.invert_y = invert_y;
+        self
+    }
+
+    pub fn with_toggle(mut self, toggle: bool) -> Self {
+        self.toggle = toggle;
+        self
+    }
+
+    pub(crate) fn apply(&self, data: ActionData) -> ActionData {
+        let mut data = data.with_axes(data.axes() * self.sensitivity);
+        if self.invert_y {
+            data = match data {
+                ActionData::Axis1D(x) => ActionData::Axis1D(-x),
+                ActionData::Axis2D(value) => ActionData::Axis2D(value * Vec2::new(1.0, -1.0)),
+                ActionData::Axis3D(value) => ActionData::Axis3D(value * Vec3::new(1.0, -1.0, 1.0)),
+            };
+        }
+        data
+    }
+}
+
+/// A player's customized bindings and [`ActionSettings`], for saving to and loading from a file.
+///
+/// Actions are keyed by their short type names. Loading never fails because of the profile's contents:
+/// actions that no longer exist are ignored, and bindings that no longer fit are skipped with a warning.
+/// Use [`ControlProfile::upgrade`] to carry old files over renamed actions.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// #[derive(Action)]
+/// struct Look;
+///
+/// const PROFILE_VERSION: u32 = 2;
+///
+/// fn save(world: &mut World, player: Entity) -> Result<ControlProfile> {
+///     let mut profile = ControlProfile::new(PROFILE_VERSION);
+///     profile.capture::<Jump>(world, player)?;
+///     profile.capture::<Look>(world, player)?;
+///     Ok(profile)
+/// }
+///
+/// fn load(mut profile: ControlProfile, world: &mut World, player: Entity) -> Result {
+///     // Look used to be called Camera
+///     profile.upgrade(2, |profile| profile.rename_action("Camera", "Look"));
+///     profile.apply::<Jump>(world, player)?;
+///     profile.apply::<Look>(world, player)?;
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct ControlProfile {
+    /// The version of the game's actions that the profile was saved with.
+    #[reflect(default)]
+    pub version: u32,
+    pub actions: HashMap<String, ActionProfile>,
+}
+
+/// The bindings and settings of one action in a [`ControlProfile`].
+#[derive(Clone, Debug, PartialEq, Reflect)]
+#[reflect(Clone, Default, Debug, PartialEq)]
+pub struct ActionProfile {
+    /// Every binding's parts, in the order [`Rebind`] indexes them.
+    pub bindings: Vec<Vec<BindingPart>>,
+    #[reflect(default = "default_sensitivity")]
+    pub sensitivity: f32,
+    #[reflect(default)]
+    pub invert_y: bool,
+    #[reflect(default)]
+    pub toggle: bool,
+}
+
+impl Default for ActionProfile {
+    fn default() -> Self {
+        Self {
+            bindings: Vec::new(),
+            sensitivity: default_sensitivity(),
+            invert_y: false,
+            toggle: false,
+        }
+    }
+}
+
+fn default_sensitivity() -> f32 {
+    1.0
+}
+
+impl ControlProfile {
+    pub fn new(version: u32) -> Self {
+        Self {
+            version,
+            actions: HashMap::default(),
+        }
+    }
+
+    /// The entry for the [`Action`], found by its [`ShortName`].
+    pub fn get<A: Action>(&self) -> Option<&ActionProfile> {
+        self.actions.get(&ShortName::of::<A>().to_string())
+    }
+
+    /// Records the action's current bindings and settings on the input system entity, replacing any previous entry.
+    pub fn capture<A: Action>(&mut self, world: &mut World, input: Entity) -> Result {
+        let bindings = SystemState::<InputBindings<A>>::new(world)
+            .get(world)
+            .get(input)?;
+        let mut profile = ActionProfile {
+            bindings,
+            ..default()
+        };
+        if let Some(settings) = world.get::<ActionSettings<A>>(input) {
+            profile.sensitivity = settings.sensitivity;
+            profile.invert_y = settings.invert_y;
+            profile.toggle = settings.toggle;
+        }
+        self.actions
+            .insert(ShortName::of::<A>().to_string(), profile);
+        Ok(())
+    }
+
+    /// Rebinds the action on the input system entity and sets its [`ActionSettings`] from the profile.
+    ///
+    /// Does nothing if the profile has no entry for the action.
+    pub fn apply<A: Action>(&self, world: &mut World, input: Entity) -> Result {
+        let Some(profile) = self.get::<A>() else {
+            return Ok(());
+        };
+
+        let current = SystemState::<InputBindings<A>>::new(world)
+            .get(world)
+            .get(input)?;
+        if profile.bindings.len() != current.len() {
+            warn!(
+                "Control profile has {} bindings for {}, which has {}",
+                profile.bindings.len(),
+                ShortName::of::<A>(),
+                current.len()
+            );
+        }
+        for (index, (parts, current)) in profile.bindings.iter().zip(&current).enumerate() {
+            if parts.len() != current.len() {
+                warn!(
+                    "Skipping binding {} of {} in control profile, expected {} parts but found {}",
+                    index,
+                    ShortName::of::<A>(),
+                    current.len(),
+                    parts.len()
+                );
+                continue;
+            }
+            Rebind::<A>::new(index, parts.iter().copied()).apply(world.entity_mut(input))?;
+        }
+
+        world.entity_mut(input).insert(ActionSettings::<A> {
+            sensitivity: profile.sensitivity,
+            invert_y: profile.invert_y,
+            toggle: profile.toggle,
+            _marker: PhantomData,
+        });
+        Ok(())
+    }
+
+    /// Moves an action's entry to a new name, such as after renaming its type.
+    pub fn rename_action(&mut self, old: &str, new: &str) {
+        if let Some(action) = self.actions.remove(old) {
+            self.actions.insert(new.to_string(), action);
+        }
+    }
+
+    /// Runs the migration if the profile is older than the version, then marks it as that version.
+    ///
+    /// Call it once per version in ascending order, so old files go through every migration since they were saved.
+    pub fn upgrade(&mut self, version: u32, migrate: impl FnOnce(&mut Self)) {
+        if self.version < version {
+            migrate(self);
+            self.version = version;
+        }
+    }
+
+    /// Writes the profile as RON, through reflection.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// #[derive(Action)]
+    /// struct Jump;
+    ///
+    /// # fn main() -> Result {
+    /// let mut app = App::new();
+    /// app.add_plugins(PrettyNiceInputPlugin);
+    /// let world = app.world_mut();
+    /// let player = world
+    ///     .spawn(input!(Jump, Axis1D[binding1d::space(), binding1d::south()]))
+    ///     .id();
+    /// let east = BindingPart::GamepadButton(GamepadButton::East);
+    /// Rebind::<Jump>::new(1, [east]).apply(world.entity_mut(player))?;
+    ///
+    /// let mut profile = ControlProfile::new(1);
+    /// profile.capture::<Jump>(world, player)?;
+    /// let registry = world.resource::<AppTypeRegistry>().clone();
+    /// let text = profile.to_ron(&registry.read())?;
+    ///
+    /// ResetBindings::<Jump>::default().apply(world.entity_mut(player))?;
+    /// ControlProfile::from_ron(&text, &registry.read())?.apply::<Jump>(world, player)?;
+    ///
+    /// let bindings = bevy::ecs::system::SystemState::<InputBindings<Jump>>::new(world)
+    ///     .get(world)
+    ///     .get(player)?;
+    /// assert_eq!(bindings, vec![vec![BindingPart::Key(KeyCode::Space)], vec![east]]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "serialize")]
+    pub fn to_ron(&self, registry: &bevy::reflect::TypeRegistry) -> Result<String> {
+        let serializer = bevy::reflect::serde::TypedReflectSerializer::new(self, registry);
+        Ok(ron::ser::to_string_pretty(
+            &serializer,
+            ron::ser::PrettyConfig::default(),
+        )?)
+    }
+
+    /// Reads a profile written by [`ControlProfile::to_ron`].
+    #[cfg(feature = "serialize")]
+    pub fn from_ron(text: &str, registry: &bevy::reflect::TypeRegistry) -> Result<Self> {
+        use serde::de::DeserializeSeed;
+
+        let mut deserializer = ron::Deserializer::from_str(text)?;
+        let profile = bevy::reflect::serde::TypedReflectDeserializer::of::<Self>(registry)
+            .deserialize(&mut deserializer)?;
+        Self::from_reflect(&*profile)
+            .ok_or_else(|| BevyError::from("Control profile doesn't match the ControlProfile type"))
+    }
+}
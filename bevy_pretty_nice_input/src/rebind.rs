@@ -0,0 +1,485 @@
+use std::marker::PhantomData;
+
+use bevy::ecs::relationship::Relationship;
+use bevy::ecs::system::{SystemParam, SystemState};
+use bevy::input::gamepad::{GamepadAxisChangedEvent, GamepadButtonChangedEvent};
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::mouse::MouseButtonInput;
+use bevy::prelude::*;
+
+use crate::actions::{Action, Actions};
+use crate::binding_parts::{
+    BindingPart, BindingPartData, BindingPartOf, BindingPartQuery, BindingPartUpdate, BindingParts,
+    OwnedGamepad,
+};
+use crate::bindings::Bindings;
+use crate::conflicts::BindingConflicts;
+use crate::display::{BindingDisplay, BindingGlyphs, InputDevice};
+use crate::profile::ActionSettings;
+
+/// [`EntityCommand`] that replaces the binding parts of one of an [`Action`]'s bindings on an input system entity.
+///
+/// Bindings are indexed in the order they were given to [`input!`](crate::prelude::input), continuing through each
+/// `Actions<A>` entity if the input system entity has more than one, the same as [`InputBindings::get`] lists them.
+/// Their parts are indexed in the order of their dimension's axes.
+/// Parts keep their [`Modifier`](crate::prelude::Modifier)s, and a binding that was being held is released.
+/// [`ResetBindings`] undoes every rebind of the action.
+///
+/// By default, other actions on the input system entity are left alone even if they read the same input.
+/// Use [`Rebind::on_conflict`] to reject the rebind or swap inputs with them instead.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// fn rebind_jump(player: Entity, mut commands: Commands) {
+///     commands
+///         .entity(player)
+///         .queue(Rebind::<Jump>::new(0, [BindingPart::Key(KeyCode::KeyJ)]));
+/// }
+/// ```
+pub struct Rebind<A: Action> {
+    binding: usize,
+    parts: RebindParts,
+    conflicts: RebindConflicts,
+    marker: PhantomData<A>,
+}
+
+/// What [`Rebind`] does when a new binding part overlaps another action's, as found by [`BindingConflicts`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Clone, Debug, Default, PartialEq)]
+pub enum RebindConflicts {
+    /// Rebind anyway, leaving both actions on the input.
+    #[default]
+    Allow,
+    /// Fail without changing anything.
+    Reject,
+    /// Give the other actions the binding part that was replaced, so the two swap inputs.
+    ///
+    /// Only binding parts of the same kind swap, like two keys or two key axes, except that one side of a key
+    /// or gamepad button axis can swap with a single key or button. Anything else fails without changing anything.
+    Swap,
+}
+
+enum RebindParts {
+    All(Vec<BindingPart>),
+    One(usize, BindingPart),
+}
+
+impl<A: Action> Rebind<A> {
+    /// Replaces every part of the binding. There must be as many parts as the binding's dimension.
+    pub fn new(binding: usize, parts: impl IntoIterator<Item = BindingPart>) -> Self {
+        Self {
+            binding,
+            parts: RebindParts::All(parts.into_iter().collect()),
+            conflicts: RebindConflicts::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Replaces a single part of the binding, like only the Y axis of a 2D binding.
+    pub fn part(binding: usize, part: usize, binding_part: BindingPart) -> Self {
+        Self {
+            binding,
+            parts: RebindParts::One(part, binding_part),
+            conflicts: RebindConflicts::default(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets what happens when the new binding parts are already used by other actions.
+    ///
+    /// ```rust
+    /// # use bevy::prelude::*;
+    /// # use bevy_pretty_nice_input::prelude::*;
+    /// # #[derive(Action)]
+    /// # struct Jump;
+    /// fn rebind_jump(player: Entity, mut commands: Commands) {
+    ///     commands.entity(player).queue(
+    ///         Rebind::<Jump>::new(0, [BindingPart::Key(KeyCode::KeyE)])
+    ///             .on_conflict(RebindConflicts::Swap),
+    ///     );
+    /// }
+    /// ```
+    pub fn on_conflict(mut self, conflicts: RebindConflicts) -> Self {
+        self.conflicts = conflicts;
+        self
+    }
+}
+
+impl<A: Action> EntityCommand<Result> for Rebind<A> {
+    fn apply(self, entity: EntityWorldMut) -> Result {
+        let input = entity.id();
+        let world = entity.into_world_mut();
+
+        if !world.entity(input).contains::<Actions<A>>() {
+            return Err(BevyError::from(format!(
+                "{} has no {} action",
+                input,
+                ShortName::of::<A>()
+            )));
+        }
+        let bindings = action_bindings::<A>(world, input);
+        let &(action, binding) = bindings.get(self.binding).ok_or_else(|| {
+            BevyError::from(format!(
+                "{} has {} bindings, found index {}",
+                ShortName::of::<A>(),
+                bindings.len(),
+                self.binding
+            ))
+        })?;
+        let binding_parts = world
+            .get::<BindingParts>(binding)
+            .ok_or_else(|| BevyError::from("Binding has no binding parts"))?
+            .collection()
+            .clone();
+
+        let replacements = match self.parts {
+            RebindParts::All(parts) => {
+                if parts.len() != binding_parts.len() {
+                    return Err(BevyError::from(format!(
+                        "Expected {} binding parts, found {}",
+                        binding_parts.len(),
+                        parts.len()
+                    )));
+                }
+                binding_parts.into_iter().zip(parts).collect::<Vec<_>>()
+            }
+            RebindParts::One(index, part) => {
+                let binding_part = binding_parts.get(index).copied().ok_or_else(|| {
+                    BevyError::from(format!(
+                        "Expected binding part index below {}, found {}",
+                        binding_parts.len(),
+                        index
+                    ))
+                })?;
+                vec![(binding_part, part)]
+            }
+        };
+
+        let swaps = match self.conflicts {
+            RebindConflicts::Allow => Vec::new(),
+            conflicts => {
+                let bound = SystemState::<BindingConflicts>::new(world)
+                    .get(world)
+                    .bound_parts(input);
+                let mut swaps = Vec::new();
+                for (binding_part, part) in &replacements {
+                    let old = bound
+                        .iter()
+                        .find(|bound| bound.entity == *binding_part)
+                        .map(|bound| bound.part);
+                    for other in bound
+                        .iter()
+                        .filter(|bound| bound.action != action && bound.part.overlaps(part))
+                    {
+                        let swapped = match (conflicts, old) {
+                            (RebindConflicts::Swap, Some(old)) => other.part.swapped(*part, old),
+                            _ => None,
+                        };
+                        let Some(swapped) = swapped else {
+                            return Err(BevyError::from(format!(
+                                "{} is already bound to {}{}",
+                                part.name(),
+                                world
+                                    .get::<Name>(other.action)
+                                    .map(|name| name.to_string())
+                                    .unwrap_or_else(|| other.action.to_string()),
+                                if conflicts == RebindConflicts::Swap {
+                                    format!(" as {}, which can't be swapped", other.part.name())
+                                } else {
+                                    String::new()
+                                }
+                            )));
+                        };
+                        swaps.push((other.entity, swapped));
+                    }
+                }
+                swaps
+            }
+        };
+
+        replace_binding_parts(world, binding, replacements);
+        for (binding_part, part) in swaps {
+            let Some(binding) = world
+                .get::<BindingPartOf>(binding_part)
+                .map(BindingPartOf::get)
+            else {
+                continue;
+            };
+            replace_binding_parts(world, binding, vec![(binding_part, part)]);
+        }
+        Ok(())
+    }
+}
+
+/// [`EntityCommand`] that undoes every [`Rebind`] of an [`Action`] on an input system entity,
+/// and resets its [`ActionSettings`] if it has them, to restore the defaults.
+pub struct ResetBindings<A: Action>(PhantomData<A>);
+
+impl<A: Action> Default for ResetBindings<A> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<A: Action> EntityCommand<Result> for ResetBindings<A> {
+    fn apply(self, mut entity: EntityWorldMut) -> Result {
+        if let Some(mut settings) = entity.get_mut::<ActionSettings<A>>() {
+            *settings = ActionSettings::default();
+        }
+
+        let input = entity.id();
+        let world = entity.into_world_mut();
+        for (_, binding) in action_bindings::<A>(world, input) {
+            let Some(binding_parts) = world.get::<BindingParts>(binding) else {
+                continue;
+            };
+            let replacements = binding_parts
+                .collection()
+                .iter()
+                .filter_map(|&binding_part| {
+                    let default = world.get::<DefaultBindingPart>(binding_part)?.0;
+                    Some((binding_part, default))
+                })
+                .collect::<Vec<_>>();
+            replace_binding_parts(world, binding, replacements);
+        }
+        Ok(())
+    }
+}
+
+/// Every binding of every `Actions<A>` entity on the input system entity, with the action it's on.
+///
+/// Bindings are indexed into this by [`Rebind`] and listed in the same order by [`InputBindings::get`].
+fn action_bindings<A: Action>(world: &World, input: Entity) -> Vec<(Entity, Entity)> {
+    let Some(actions) = world.get::<Actions<A>>(input) else {
+        return Vec::new();
+    };
+    actions
+        .collection()
+        .iter()
+        .filter_map(|&action| Some((action, world.get::<Bindings>(action)?)))
+        .flat_map(|(action, bindings)| {
+            bindings
+                .collection()
+                .iter()
+                .map(move |&binding| (action, binding))
+        })
+        .collect()
+}
+
+/// The binding part that a binding part entity was spawned as, remembered by [`Rebind`] for [`ResetBindings`].
+#[derive(Component, Clone, Copy, Debug, Reflect)]
+#[reflect(Component, Clone, Debug)]
+pub(crate) struct DefaultBindingPart(pub BindingPart);
+
+/// Turns binding part entities into new binding parts, releasing the binding if it was held.
+fn replace_binding_parts(
+    world: &mut World,
+    binding: Entity,
+    replacements: Vec<(Entity, BindingPart)>,
+) {
+    let mut parts = world.query::<BindingPartQuery>();
+    let mut released = None;
+    for (binding_part, part) in replacements {
+        let current = parts
+            .get(world, binding_part)
+            .ok()
+            .and_then(|current| current.binding_part());
+        if current == Some(part) {
+            continue;
+        }
+
+        let mut entity = world.entity_mut(binding_part);
+        if !entity.contains::<DefaultBindingPart>()
+            && let Some(current) = current
+        {
+            entity.insert(DefaultBindingPart(current));
+        }
+        if entity
+            .get::<BindingPartData>()
+            .is_some_and(|data| data.0 != 0.0)
+        {
+            released = Some(binding_part);
+        }
+        part.insert_into(&mut entity);
+    }
+
+    // The binding recalculates from every part, so one update releases it
+    if let Some(binding_part) = released {
+        world.trigger(BindingPartUpdate {
+            binding,
+            binding_part,
+            value: 0.0,
+        });
+    }
+}
+
+/// Reads the binding parts of an [`Action`]'s bindings, like for a controls settings screen.
+#[derive(SystemParam)]
+pub struct InputBindings<'w, 's, A: Action> {
+    actions: Query<'w, 's, &'static Actions<A>>,
+    bindings: Query<'w, 's, &'static Bindings>,
+    binding_parts: Query<'w, 's, &'static BindingParts>,
+    parts: Query<'w, 's, BindingPartQuery>,
+}
+
+impl<A: Action> InputBindings<'_, '_, A> {
+    /// Every binding of the action on the input system entity, each with its binding parts, in the order [`Rebind`] indexes them.
+    pub fn get(&self, input: Entity) -> Result<Vec<Vec<BindingPart>>> {
+        let actions = self.actions.get(input)?;
+        let mut result = Vec::new();
+        for action in actions.collection() {
+            for binding in self.bindings.get(*action)?.collection() {
+                let parts = self
+                    .parts
+                    .iter_many(self.binding_parts.get(*binding)?.collection())
+                    .map(|part| {
+                        part.binding_part()
+                            .ok_or_else(|| BevyError::from("Unknown binding part"))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                result.push(parts);
+            }
+        }
+        Ok(result)
+    }
+
+    /// The first binding of the action on the input system entity that only uses the given devices, as text for showing to players.
+    ///
+    /// `None` if no binding fits, like when looking for a gamepad binding of a keyboard-only action. See [`BindingDisplay`].
+    pub fn prompt(
+        &self,
+        input: Entity,
+        devices: &[InputDevice],
+        glyphs: &BindingGlyphs,
+    ) -> Result<Option<String>> {
+        Ok(self
+            .get(input)?
+            .into_iter()
+            .find(|binding| binding.iter().all(|part| devices.contains(&part.device())))
+            .map(|binding| binding.display(glyphs)))
+    }
+}
+
+/// Component that listens for the next key, mouse button, gamepad button or gamepad axis moved past a threshold.
+///
+/// When it hears one, it triggers [`InputCaptured`] on its entity and removes itself.
+/// If the entity has an [`OwnedGamepad`], only that gamepad is listened to.
+///
+/// The captured input still reaches actions as usual, so disable them with [`InputDisabled`](crate::prelude::InputDisabled) while capturing if that matters.
+///
+/// ```rust
+/// # use bevy::prelude::*;
+/// # use bevy_pretty_nice_input::prelude::*;
+/// #[derive(Action)]
+/// struct Jump;
+///
+/// #[derive(Component)]
+/// struct Player;
+///
+/// fn start_rebinding_jump(mut commands: Commands) {
+///     commands.spawn(CaptureInput::default()).observe(
+///         |captured: On<InputCaptured>, player: Single<Entity, With<Player>>, mut commands: Commands| {
+///             commands
+///                 .entity(*player)
+///                 .queue(Rebind::<Jump>::new(0, [captured.part]));
+///             commands.entity(captured.entity).despawn();
+///         },
+///     );
+/// }
+/// ```
+#[derive(Component, Clone, Debug, Reflect)]
+#[reflect(Component, Clone, Default, Debug)]
+pub struct CaptureInput {
+    pub keyboard: bool,
+    pub mouse: bool,
+    pub gamepad: bool,
+    /// How far a gamepad axis has to move to be captured.
+    pub axis_threshold: f32,
+}
+
+impl Default for CaptureInput {
+    fn default() -> Self {
+        Self {
+            keyboard: true,
+            mouse: true,
+            gamepad: true,
+            axis_threshold: 0.5,
+        }
+    }
+}
+
+/// Triggered on a [`CaptureInput`] entity when it captures an input.
+#[derive(EntityEvent, Clone, Debug, Reflect)]
+#[reflect(Clone, Debug)]
+pub struct InputCaptured {
+    pub entity: Entity,
+    pub part: BindingPart,
+    /// The gamepad the input came from, if any.
+    pub gamepad: Option<Entity>,
+}
+
+pub(crate) fn capture_input(
+    capturing: Query<(Entity, &CaptureInput, Option<&OwnedGamepad>)>,
+    mut keyboard: MessageReader<KeyboardInput>,
+    mut mouse_button: MessageReader<MouseButtonInput>,
+    mut gamepad_button: MessageReader<GamepadButtonChangedEvent>,
+    mut gamepad_axis: MessageReader<GamepadAxisChangedEvent>,
+    mut commands: Commands,
+) {
+    // Every message is read even while nothing is capturing, so input from before capturing started isn't captured
+    let keys = keyboard
+        .read()
+        .filter(|message| message.state.is_pressed() && !message.repeat)
+        .map(|message| message.key_code)
+        .collect::<Vec<_>>();
+    let mouse_buttons = mouse_button
+        .read()
+        .filter(|message| message.state.is_pressed())
+        .map(|message| message.button)
+        .collect::<Vec<_>>();
+    let gamepad_buttons = gamepad_button
+        .read()
+        .filter(|message| message.state.is_pressed())
+        .map(|message| (message.entity, message.button))
+        .collect::<Vec<_>>();
+    let gamepad_axes = gamepad_axis
+        .read()
+        .map(|message| (message.entity, message.axis, message.value))
+        .collect::<Vec<_>>();
+
+    for (entity, capture, owned) in capturing.iter() {
+        let owned = owned.copied().unwrap_or_default();
+        let key = keys
+            .first()
+            .filter(|_| capture.keyboard)
+            .map(|key| (BindingPart::Key(*key), None));
+        let mouse_button = mouse_buttons
+            .first()
+            .filter(|_| capture.mouse)
+            .map(|button| (BindingPart::MouseButton(*button), None));
+        let gamepad_button = gamepad_buttons
+            .iter()
+            .find(|(gamepad, _)| capture.gamepad && owned.accepts(*gamepad))
+            .map(|(gamepad, button)| (BindingPart::GamepadButton(*button), Some(*gamepad)));
+        let gamepad_axis = gamepad_axes
+            .iter()
+            .find(|(gamepad, _, value)| {
+                capture.gamepad && owned.accepts(*gamepad) && value.abs() >= capture.axis_threshold
+            })
+            .map(|(gamepad, axis, _)| (BindingPart::GamepadAxis(*axis), Some(*gamepad)));
+
+        if let Some((part, gamepad)) = key.or(mouse_button).or(gamepad_button).or(gamepad_axis) {
+            commands.entity(entity).remove::<CaptureInput>();
+            commands.trigger(InputCaptured {
+                entity,
+                part,
+                gamepad,
+            });
+        }
+    }
+}
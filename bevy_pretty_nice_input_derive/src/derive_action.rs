@@ -22,6 +22,7 @@ fn action(input: DeriveInput) -> syn::Result<syn::ItemImpl> {
         .transpose()?;
 
     let mut invalidate = None;
+    let mut output = None;
 
     if let Some(meta_list) = meta_list {
         for item in meta_list {
@@ -51,6 +52,16 @@ fn action(input: DeriveInput) -> syn::Result<syn::ItemImpl> {
                         }
                     }
                 }
+                Expr::Assign(assign) if assign.left.to_token_stream().to_string() == "output" => {
+                    if output.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            assign.left,
+                            "Duplicate `output` attribute",
+                        ));
+                    }
+
+                    output = Some(syn::parse2::<Type>(assign.right.to_token_stream())?);
+                }
                 _ => {
                     return Err(syn::Error::new_spanned(item, "Unexpected item"));
                 }
@@ -64,9 +75,13 @@ fn action(input: DeriveInput) -> syn::Result<syn::ItemImpl> {
         parse_quote!(::bevy_pretty_nice_input::prelude::IsInputEnabled)
     };
 
+    let output =
+        output.unwrap_or_else(|| parse_quote!(::bevy_pretty_nice_input::prelude::ActionData));
+
     Ok(parse_quote! {
         impl ::bevy_pretty_nice_input::prelude::Action for #ident {
             type EnableFilter = #enable_filter;
+            type Output = #output;
         }
     })
 }
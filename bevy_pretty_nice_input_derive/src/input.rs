@@ -1,6 +1,7 @@
 use proc_macro::TokenStream;
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
 use syn::{Token, parse_quote};
 
 pub fn input_impl(input: TokenStream) -> TokenStream {
@@ -18,12 +19,25 @@ fn input_(mut input: Input) -> syn::Result<syn::Expr> {
     );
     let bindings = build_bindings(action, &input.bindings);
     let conditions = build_conditions(action, &input.conditions);
-    let actions = build_actions(action, &input.bindings.dim, &bindings, &conditions);
+    let actions = build_actions(
+        action,
+        &input.bindings.dim,
+        &bindings,
+        &conditions,
+        &input.options,
+    );
+    let dim = &input.bindings.dim;
+    let assert_dim = quote_spanned! {action.span()=>
+        ::bevy_pretty_nice_input::derive::assert_output_dimension::<#action, ::bevy_pretty_nice_input::dimension::#dim>();
+    };
     let output = parse_quote! {
-        (
-            #actions,
-            ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::action_enable::<#action>),
-        )
+        {
+            #assert_dim
+            (
+                #actions,
+                ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::action_enable::<#action>),
+            )
+        }
     };
     Ok(output)
 }
@@ -33,12 +47,17 @@ fn build_actions(
     binding_dim: &BindingDim,
     bindings: &syn::Expr,
     conditions: &syn::Expr,
+    options: &InputOptions,
 ) -> syn::Expr {
+    let aggregation = options.aggregation.clone().unwrap_or_else(|| {
+        parse_quote!(::bevy_pretty_nice_input::derive::BindingAggregation::default())
+    });
     parse_quote! {
         ::bevy::prelude::related!(::bevy_pretty_nice_input::derive::Actions<#action>[(
             ::bevy::prelude::Name::new(format!("{} Action", ::bevy::prelude::ShortName::of::<#action>())),
             ::bevy_pretty_nice_input::derive::PrevActionData(::bevy_pretty_nice_input::derive::ActionData::#binding_dim(Default::default())),
             ::bevy_pretty_nice_input::derive::PrevAction2Data::default(),
+            #aggregation,
             ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::action::<#action>),
             ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::action_2::<#action>),
             ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::action_2_invalidate::<#action>),
@@ -50,10 +69,28 @@ fn build_actions(
 }
 
 fn build_bindings(action: &syn::Type, bindings: &Bindings) -> syn::Expr {
-    let bindings = &bindings.bindings;
+    let dim = &bindings.dim;
+    let modifiers = bindings.bindings.iter().map(|binding| -> syn::Expr {
+        let modifiers = &binding.modifiers;
+        parse_quote! {
+            ::bevy_pretty_nice_input::derive::Modifiers(vec![#( #modifiers ),*])
+        }
+    });
+    let bindings = bindings.bindings.iter().map(|binding| {
+        let binding = &binding.binding;
+        quote_spanned! {binding.span()=>
+            {
+                let binding = #binding;
+                ::bevy_pretty_nice_input::derive::assert_binding_dimension::<::bevy_pretty_nice_input::dimension::#dim, _>(&binding);
+                binding
+            }
+        }
+    });
     parse_quote! {
         ::bevy::prelude::related!(::bevy_pretty_nice_input::derive::Bindings[#((
             ::bevy::prelude::Name::new(format!("{} Binding", ::bevy::prelude::ShortName::of::<#action>())),
+            ::bevy_pretty_nice_input::derive::BindingData::new(::bevy_pretty_nice_input::derive::ActionData::#dim(Default::default())),
+            #modifiers,
             ::bevy_pretty_nice_input::bundles::observe(::bevy_pretty_nice_input::derive::binding),
             ::bevy_pretty_nice_input::derive::BindingParts::spawn(#bindings),
         )),*])
@@ -81,6 +118,7 @@ struct Input {
     action: syn::Type,
     bindings: Bindings,
     conditions: Conditions,
+    options: InputOptions,
 }
 
 impl Parse for Input {
@@ -88,29 +126,43 @@ impl Parse for Input {
         let action = input.parse::<syn::Type>()?;
         input.parse::<Token![,]>()?;
         let bindings = input.parse::<Bindings>()?;
-        let conditions = if input.peek(Token![,]) {
-            input.parse::<Token![,]>()?;
-            let conditions = input.parse::<Conditions>().unwrap_or_default();
-            if input.peek(Token![,]) {
-                input.parse::<Token![,]>()?;
-            }
-            conditions
-        } else {
-            Conditions::default()
-        };
+        let (conditions, options) = parse_conditions_and_options(input)?;
 
         Ok(Input {
             action,
             bindings,
             conditions,
+            options,
         })
     }
 }
 
+/// Parses the optional conditions and `name = value` options after the bindings.
+pub fn parse_conditions_and_options(input: ParseStream) -> syn::Result<(Conditions, InputOptions)> {
+    let mut conditions = None;
+    let mut options = InputOptions::default();
+    while input.peek(Token![,]) {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+
+        if input.peek(syn::token::Bracket) {
+            if conditions.is_some() {
+                return Err(input.error("Duplicate conditions"));
+            }
+            conditions = Some(input.parse::<Conditions>()?);
+        } else {
+            options.parse_option(input)?;
+        }
+    }
+    Ok((conditions.unwrap_or_default(), options))
+}
+
 #[derive(Clone)]
 pub struct Bindings {
     pub dim: BindingDim,
-    pub bindings: Vec<syn::Expr>,
+    pub bindings: Vec<Binding>,
 }
 
 impl Parse for Bindings {
@@ -119,7 +171,7 @@ impl Parse for Bindings {
         let content;
         syn::bracketed!(content in input);
         let bindings = content
-            .parse_terminated(syn::Expr::parse, Token![,])?
+            .parse_terminated(Binding::parse, Token![,])?
             .into_iter()
             .collect();
         Ok(Bindings { dim, bindings })
@@ -136,6 +188,40 @@ impl ToTokens for Bindings {
     }
 }
 
+#[derive(Clone)]
+pub struct Binding {
+    pub binding: syn::Expr,
+    pub modifiers: Vec<syn::Expr>,
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let binding = input.parse::<syn::Expr>()?;
+        let modifiers = if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+            let content;
+            syn::bracketed!(content in input);
+            content
+                .parse_terminated(syn::Expr::parse, Token![,])?
+                .into_iter()
+                .collect()
+        } else {
+            vec![]
+        };
+        Ok(Binding { binding, modifiers })
+    }
+}
+
+impl ToTokens for Binding {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let binding = &self.binding;
+        let modifiers = &self.modifiers;
+        tokens.extend(quote! {
+            #binding => [ #( #modifiers ),* ]
+        });
+    }
+}
+
 #[derive(Clone)]
 pub enum BindingDim {
     Axis1D,
@@ -193,3 +279,41 @@ impl ToTokens for Conditions {
         });
     }
 }
+
+#[derive(Clone, Default)]
+pub struct InputOptions {
+    pub aggregation: Option<syn::Expr>,
+}
+
+impl InputOptions {
+    fn parse_option(&mut self, input: ParseStream) -> syn::Result<()> {
+        let name = input.parse::<syn::Ident>()?;
+        input.parse::<Token![=]>()?;
+        let value = input.parse::<syn::Expr>()?;
+        let option = match name.to_string().as_str() {
+            "aggregation" => &mut self.aggregation,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    name,
+                    "Expected one of `aggregation`",
+                ));
+            }
+        };
+        if option.is_some() {
+            return Err(syn::Error::new_spanned(
+                &name,
+                format!("Duplicate `{}` option", name),
+            ));
+        }
+        *option = Some(value);
+        Ok(())
+    }
+}
+
+impl ToTokens for InputOptions {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        if let Some(aggregation) = &self.aggregation {
+            tokens.extend(quote! { aggregation = #aggregation, });
+        }
+    }
+}
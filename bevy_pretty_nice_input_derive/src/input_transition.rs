@@ -5,7 +5,7 @@ use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 use syn::{Token, parse_quote};
 
-use crate::input::{Bindings, Conditions};
+use crate::input::{Bindings, Conditions, InputOptions, parse_conditions_and_options};
 
 pub fn input_transition_impl(input: TokenStream) -> TokenStream {
     let input = syn::parse_macro_input!(input as InputTransition);
@@ -41,9 +41,10 @@ pub fn input_transition_impl(input: TokenStream) -> TokenStream {
                 arrow,
                 bindings: input.bindings,
                 conditions: input.conditions,
+                options: input.options,
             };
 
-            match input_transition(half) {
+            match input_transition(half, FilterPosition::First) {
                 Ok(expr) => expr.into_token_stream().into(),
                 Err(err) => err.to_compile_error().into(),
             }
@@ -54,15 +55,6 @@ pub fn input_transition_impl(input: TokenStream) -> TokenStream {
             right_action,
             right,
         } => {
-            if !input.conditions.conditions.is_empty() {
-                return syn::Error::new_spanned(
-                    input.conditions.to_token_stream(),
-                    "Conditions are not supported for bidirectional transitions (`<=>`)",
-                )
-                .to_compile_error()
-                .into();
-            }
-
             let left_half = InputTransitionHalf {
                 action: left_action,
                 from: right.clone().into(),
@@ -70,8 +62,9 @@ pub fn input_transition_impl(input: TokenStream) -> TokenStream {
                 arrow: ObserverArrow::Left,
                 bindings: input.bindings.clone(),
                 conditions: input.conditions.clone(),
+                options: input.options.clone(),
             };
-            let left_expr = match input_transition(left_half) {
+            let left_expr = match input_transition(left_half, FilterPosition::Last) {
                 Ok(expr) => expr,
                 Err(err) => return err.to_compile_error().into(),
             };
@@ -83,8 +76,9 @@ pub fn input_transition_impl(input: TokenStream) -> TokenStream {
                 arrow: ObserverArrow::Right,
                 bindings: input.bindings,
                 conditions: input.conditions,
+                options: input.options,
             };
-            let right_expr = match input_transition(right_half) {
+            let right_expr = match input_transition(right_half, FilterPosition::Last) {
                 Ok(expr) => expr,
                 Err(err) => return err.to_compile_error().into(),
             };
@@ -101,11 +95,23 @@ pub fn input_transition_impl(input: TokenStream) -> TokenStream {
     }
 }
 
-fn input_transition(mut input: InputTransitionHalf) -> syn::Result<syn::Expr> {
-    input
-        .conditions
-        .conditions
-        .insert(0, build_filter(&input.from.query_filter()));
+/// Where the *from* filter goes relative to the user's conditions.
+///
+/// Bidirectional transitions put it last so that both halves' conditions see the same updates.
+enum FilterPosition {
+    First,
+    Last,
+}
+
+fn input_transition(
+    mut input: InputTransitionHalf,
+    filter_position: FilterPosition,
+) -> syn::Result<syn::Expr> {
+    let filter = build_filter(&input.from.query_filter());
+    match filter_position {
+        FilterPosition::First => input.conditions.conditions.insert(0, filter),
+        FilterPosition::Last => input.conditions.conditions.push(filter),
+    }
 
     let observers = build_observers(
         input.action.action(),
@@ -119,6 +125,7 @@ fn input_transition(mut input: InputTransitionHalf) -> syn::Result<syn::Expr> {
         &input.action,
         &input.bindings,
         &input.conditions,
+        &input.options,
         &observers,
     ))
 }
@@ -127,6 +134,7 @@ fn build_output(
     action: &TransitionFromAction,
     bindings: &Bindings,
     conditions: &Conditions,
+    options: &InputOptions,
     observers: &[syn::Expr],
 ) -> syn::Expr {
     let inner: syn::Expr = parse_quote! {
@@ -135,6 +143,7 @@ fn build_output(
                 #action,
                 #bindings,
                 #conditions,
+                #options
             ),
             #( #observers ),*
         )
@@ -217,6 +226,7 @@ struct InputTransition {
     transition: Transition,
     bindings: Bindings,
     conditions: Conditions,
+    options: InputOptions,
 }
 
 struct InputTransitionHalf {
@@ -226,6 +236,7 @@ struct InputTransitionHalf {
     arrow: ObserverArrow,
     bindings: Bindings,
     conditions: Conditions,
+    options: InputOptions,
 }
 
 impl InputTransitionHalf {
@@ -258,21 +269,13 @@ impl Parse for InputTransition {
         let transition = input.parse::<Transition>()?;
         input.parse::<Token![,]>()?;
         let bindings = input.parse::<Bindings>()?;
-        let conditions = if input.peek(Token![,]) {
-            input.parse::<Token![,]>()?;
-            let conditions = input.parse::<Conditions>().unwrap_or_default();
-            if input.peek(Token![,]) {
-                input.parse::<Token![,]>()?;
-            }
-            conditions
-        } else {
-            Conditions::default()
-        };
+        let (conditions, options) = parse_conditions_and_options(input)?;
 
         Ok(InputTransition {
             transition,
             bindings,
             conditions,
+            options,
         })
     }
 }